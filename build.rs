@@ -0,0 +1,852 @@
+//! Generates product tables of geometric algebra elements.
+//!
+//! Each algebra is described by squares of its basis vectors
+//! and element types with the blades they store.
+//! `Mul` (geometric), `BitOr` (inner) and `BitXor` (outer) impls
+//! for every pair of element types are derived from the Cayley table
+//! and written to `OUT_DIR`.
+//!
+//! Element types of the projective algebras are written by hand,
+//! while for the other algebras they are generated as well.
+
+use std::{env, fmt::Write, fs, path::Path};
+
+/// Geometric algebra description.
+struct Algebra {
+    /// Name used in docs of generated types.
+    title: &'static str,
+
+    /// Name of the generated file.
+    file: &'static str,
+
+    /// Basis vectors with their squares.
+    basis: &'static [(char, i8)],
+
+    /// Element types.
+    elements: &'static [Element],
+}
+
+/// Element type of an algebra.
+struct Element {
+    /// Name of the struct.
+    name: &'static str,
+
+    /// Blade of each field in declaration order.
+    ///
+    /// Empty blade is the scalar stored as tuple struct field.
+    blades: &'static [&'static str],
+}
+
+/// Projective geometric algebra of the plane, Cl(2,0,1).
+const PGA2: Algebra = Algebra {
+    title: "projective geometric algebra of the plane",
+    file: "pga2.rs",
+    basis: &[('0', 0), ('1', 1), ('2', 1)],
+    elements: &[
+        Element {
+            name: "Scalar2",
+            blades: &[""],
+        },
+        Element {
+            name: "Vector2",
+            blades: &["e0", "e1", "e2"],
+        },
+        Element {
+            name: "BiVector2",
+            blades: &["e01", "e20", "e12"],
+        },
+        Element {
+            name: "Pseudo2",
+            blades: &["e012"],
+        },
+    ],
+};
+
+/// Projective geometric algebra of the space, Cl(3,0,1).
+const PGA3: Algebra = Algebra {
+    title: "projective geometric algebra of the space",
+    file: "pga3.rs",
+    basis: &[('0', 0), ('1', 1), ('2', 1), ('3', 1)],
+    elements: &[
+        Element {
+            name: "Scalar3",
+            blades: &[""],
+        },
+        Element {
+            name: "Vector3",
+            blades: &["e0", "e1", "e2", "e3"],
+        },
+        Element {
+            name: "XBiVector3",
+            blades: &["e01", "e02", "e03"],
+        },
+        Element {
+            name: "EBiVector3",
+            blades: &["e12", "e31", "e23"],
+        },
+        Element {
+            name: "BiVector3",
+            blades: &["e01", "e02", "e03", "e12", "e31", "e23"],
+        },
+        Element {
+            name: "TriVector3",
+            blades: &["e021", "e013", "e032", "e123"],
+        },
+        Element {
+            name: "Pseudo3",
+            blades: &["e0123"],
+        },
+    ],
+};
+
+/// Element types of projective algebras of the space with non-degenerate metric.
+///
+/// Same blades as in [`PGA3`].
+const PROJECTIVE3: &[Element] = &[
+    Element {
+        name: "Scalar",
+        blades: &[""],
+    },
+    Element {
+        name: "Vector",
+        blades: &["e0", "e1", "e2", "e3"],
+    },
+    Element {
+        name: "BiVector",
+        blades: &["e01", "e02", "e03", "e12", "e31", "e23"],
+    },
+    Element {
+        name: "TriVector",
+        blades: &["e021", "e013", "e032", "e123"],
+    },
+    Element {
+        name: "Pseudo",
+        blades: &["e0123"],
+    },
+];
+
+/// Elliptic projective geometric algebra of the space, Cl(4,0,0).
+const EPGA3: Algebra = Algebra {
+    title: "elliptic projective geometric algebra of the space",
+    file: "epga3.rs",
+    basis: &[('0', 1), ('1', 1), ('2', 1), ('3', 1)],
+    elements: PROJECTIVE3,
+};
+
+/// Hyperbolic projective geometric algebra of the space, Cl(3,1,0).
+const HPGA3: Algebra = Algebra {
+    title: "hyperbolic projective geometric algebra of the space",
+    file: "hpga3.rs",
+    basis: &[('0', -1), ('1', 1), ('2', 1), ('3', 1)],
+    elements: PROJECTIVE3,
+};
+
+/// Vanilla geometric algebra of the space, Cl(3,0,0).
+const VGA3: Algebra = Algebra {
+    title: "vanilla geometric algebra of the space",
+    file: "vga3.rs",
+    basis: &[('1', 1), ('2', 1), ('3', 1)],
+    elements: &[
+        Element {
+            name: "Scalar",
+            blades: &[""],
+        },
+        Element {
+            name: "Vector",
+            blades: &["e1", "e2", "e3"],
+        },
+        Element {
+            name: "BiVector",
+            blades: &["e12", "e31", "e23"],
+        },
+        Element {
+            name: "Pseudo",
+            blades: &["e123"],
+        },
+    ],
+};
+
+/// Spacetime algebra, Cl(1,3).
+///
+/// Time basis vector `e0` squares to 1 and space basis vectors square to -1.
+const STA: Algebra = Algebra {
+    title: "spacetime algebra",
+    file: "sta.rs",
+    basis: &[('0', 1), ('1', -1), ('2', -1), ('3', -1)],
+    elements: &[
+        Element {
+            name: "Scalar",
+            blades: &[""],
+        },
+        Element {
+            name: "Vector",
+            blades: &["e0", "e1", "e2", "e3"],
+        },
+        Element {
+            name: "BiVector",
+            blades: &["e01", "e02", "e03", "e23", "e31", "e12"],
+        },
+        Element {
+            name: "TriVector",
+            blades: &["e123", "e032", "e013", "e021"],
+        },
+        Element {
+            name: "Pseudo",
+            blades: &["e0123"],
+        },
+    ],
+};
+
+/// Conformal geometric algebra of the space, Cl(4,1).
+///
+/// Basis vector `e4` squares to 1 and `e5` squares to -1.
+const CGA3: Algebra = Algebra {
+    title: "conformal geometric algebra of the space",
+    file: "cga3.rs",
+    basis: &[('1', 1), ('2', 1), ('3', 1), ('4', 1), ('5', -1)],
+    elements: &[
+        Element {
+            name: "Scalar",
+            blades: &[""],
+        },
+        Element {
+            name: "Vector",
+            blades: &["e1", "e2", "e3", "e4", "e5"],
+        },
+        Element {
+            name: "BiVector",
+            blades: &[
+                "e12", "e13", "e14", "e15", "e23", "e24", "e25", "e34", "e35", "e45",
+            ],
+        },
+        Element {
+            name: "TriVector",
+            blades: &[
+                "e123", "e124", "e125", "e134", "e135", "e145", "e234", "e235", "e245", "e345",
+            ],
+        },
+        Element {
+            name: "QuadVector",
+            blades: &["e1234", "e1235", "e1245", "e1345", "e2345"],
+        },
+        Element {
+            name: "Pseudo",
+            blades: &["e12345"],
+        },
+    ],
+};
+
+/// Product operator.
+#[derive(Clone, Copy)]
+enum Product {
+    Geometric,
+    Inner,
+    Outer,
+}
+
+impl Product {
+    const ALL: [Product; 3] = [Product::Geometric, Product::Inner, Product::Outer];
+
+    fn trait_name(self) -> &'static str {
+        match self {
+            Product::Geometric => "Mul",
+            Product::Inner => "BitOr",
+            Product::Outer => "BitXor",
+        }
+    }
+
+    fn method(self) -> &'static str {
+        match self {
+            Product::Geometric => "mul",
+            Product::Inner => "bitor",
+            Product::Outer => "bitxor",
+        }
+    }
+
+    /// Returns true if product of blades with given grades keeps the result grade.
+    fn keeps(self, lhs: u32, rhs: u32, result: u32) -> bool {
+        match self {
+            Product::Geometric => true,
+            Product::Inner => result == lhs.abs_diff(rhs),
+            Product::Outer => result == lhs + rhs,
+        }
+    }
+}
+
+/// Blade in canonical form, a bitmask of basis vectors in ascending order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Blade(u32);
+
+impl Blade {
+    fn grade(self) -> u32 {
+        self.0.count_ones()
+    }
+}
+
+impl Algebra {
+    /// Parses blade name into canonical blade and sign of the name relative to it.
+    fn parse(&self, name: &str) -> (Blade, i32) {
+        if name.is_empty() {
+            return (Blade(0), 1);
+        }
+
+        let digits = name
+            .strip_prefix('e')
+            .unwrap_or_else(|| panic!("Blade `{name}` must start with `e`"));
+
+        let mut indices = Vec::new();
+        for c in digits.chars() {
+            let index = self
+                .basis
+                .iter()
+                .position(|&(b, _)| b == c)
+                .unwrap_or_else(|| panic!("Unknown basis vector `{c}` in `{name}`"));
+            assert!(
+                !indices.contains(&index),
+                "Repeated basis vector in `{name}`"
+            );
+            indices.push(index);
+        }
+
+        // Sign of the permutation sorting the indices.
+        let mut sign = 1;
+        for i in 0..indices.len() {
+            for j in i + 1..indices.len() {
+                if indices[i] > indices[j] {
+                    sign = -sign;
+                }
+            }
+        }
+
+        let mask = indices.iter().fold(0, |mask, &i| mask | 1 << i);
+        (Blade(mask), sign)
+    }
+
+    /// Multiplies canonical blades.
+    ///
+    /// Returns the sign of the result, zero if it vanishes.
+    fn multiply(&self, a: Blade, b: Blade) -> (i32, Blade) {
+        // Count swaps needed to move basis vectors of `b` past those of `a`.
+        let mut swaps = 0;
+        let mut rest = a.0 >> 1;
+        while rest != 0 {
+            swaps += (rest & b.0).count_ones();
+            rest >>= 1;
+        }
+        let mut sign = if swaps % 2 == 0 { 1 } else { -1 };
+
+        for (i, &(_, square)) in self.basis.iter().enumerate() {
+            if a.0 & b.0 & (1 << i) != 0 {
+                sign *= i32::from(square);
+            }
+        }
+
+        (sign, Blade(a.0 ^ b.0))
+    }
+
+    /// Verifies that the Cayley table is consistent with the signature
+    /// and element types are well-formed.
+    fn check(&self) {
+        let n = self.basis.len();
+        let blades = (0..1u32 << n).map(Blade);
+
+        for (i, &(_, square)) in self.basis.iter().enumerate() {
+            let e = Blade(1 << i);
+            assert_eq!(self.multiply(e, e), (i32::from(square), Blade(0)));
+
+            for j in 0..n {
+                if i != j {
+                    let f = Blade(1 << j);
+                    let (ef, r) = self.multiply(e, f);
+                    let (fe, _) = self.multiply(f, e);
+                    assert_eq!(ef, -fe, "Basis vectors must anticommute");
+                    assert_eq!(r, Blade(e.0 | f.0));
+                }
+            }
+        }
+
+        for a in blades.clone() {
+            for b in blades.clone() {
+                for c in blades.clone() {
+                    let (s1, ab) = self.multiply(a, b);
+                    let (s2, ab_c) = self.multiply(ab, c);
+                    let (s3, bc) = self.multiply(b, c);
+                    let (s4, a_bc) = self.multiply(a, bc);
+                    assert_eq!(ab_c, a_bc);
+                    assert_eq!(s1 * s2, s3 * s4, "Product must be associative");
+                }
+            }
+        }
+
+        for element in self.elements {
+            let grade = self.grade(element);
+            for (i, name) in element.blades.iter().enumerate() {
+                let (blade, _) = self.parse(name);
+                assert_eq!(blade.grade(), grade, "`{}` mixes grades", element.name);
+                for other in &element.blades[..i] {
+                    assert!(
+                        self.parse(other).0 != blade,
+                        "`{}` repeats blade",
+                        element.name
+                    );
+                }
+            }
+        }
+    }
+
+    fn grade(&self, element: &Element) -> u32 {
+        self.parse(element.blades[0]).0.grade()
+    }
+
+    /// Finds the smallest element type storing all the blades.
+    fn covering(&self, blades: &[Blade]) -> &Element {
+        self.elements
+            .iter()
+            .filter(|element| {
+                blades
+                    .iter()
+                    .all(|&b| element.blades.iter().any(|name| self.parse(name).0 == b))
+            })
+            .min_by_key(|element| element.blades.len())
+            .unwrap_or_else(|| panic!("No element type stores product blades"))
+    }
+
+    /// Generates product impl for the pair of element types.
+    ///
+    /// Returns `None` if the product is identically zero.
+    fn product(&self, product: Product, lhs: &Element, rhs: &Element) -> Option<String> {
+        let lhs_grade = self.grade(lhs);
+        let rhs_grade = self.grade(rhs);
+
+        // Terms of the product as (sign, lhs field, rhs field, result blade).
+        let mut terms = Vec::new();
+        for (i, a) in lhs.blades.iter().enumerate() {
+            let (a, sa) = self.parse(a);
+            for (j, b) in rhs.blades.iter().enumerate() {
+                let (b, sb) = self.parse(b);
+                let (s, r) = self.multiply(a, b);
+                if s != 0 && product.keeps(lhs_grade, rhs_grade, r.grade()) {
+                    terms.push((sa * sb * s, i, j, r));
+                }
+            }
+        }
+
+        if terms.is_empty() {
+            return None;
+        }
+
+        let mut grades: Vec<u32> = terms.iter().map(|t| t.3.grade()).collect();
+        grades.sort_unstable();
+        grades.dedup();
+
+        let mut parts = Vec::new();
+        let mut types = Vec::new();
+        for grade in grades {
+            let blades: Vec<Blade> = terms
+                .iter()
+                .filter(|t| t.3.grade() == grade)
+                .map(|t| t.3)
+                .collect();
+            let output = self.covering(&blades);
+            types.push(format!("{}<T>", output.name));
+
+            let fields: Vec<(String, String)> = output
+                .blades
+                .iter()
+                .map(|name| {
+                    let (blade, sign) = self.parse(name);
+                    let mut expr = String::new();
+                    for &(s, i, j, _) in terms.iter().filter(|t| t.3 == blade) {
+                        let s = s * sign;
+                        let term = format!(
+                            "self.{} * other.{}",
+                            field(lhs.blades[i]),
+                            field(rhs.blades[j])
+                        );
+                        match (expr.is_empty(), s > 0) {
+                            (true, true) => expr = term,
+                            (true, false) => expr = format!("-{term}"),
+                            (false, true) => write!(expr, " + {term}").unwrap(),
+                            (false, false) => write!(expr, " - {term}").unwrap(),
+                        }
+                    }
+                    if expr.is_empty() {
+                        expr = "T::ZERO".to_owned();
+                    }
+                    (field(name), expr)
+                })
+                .collect();
+
+            parts.push(construct(output.name, &fields));
+        }
+
+        let (output, body) = if types.len() == 1 {
+            (types.remove(0), parts.remove(0))
+        } else {
+            (
+                format!("({})", types.join(", ")),
+                format!("({})", parts.join(", ")),
+            )
+        };
+
+        let allow = match product {
+            Product::Geometric => "",
+            _ => "\n    #[allow(clippy::suspicious_arithmetic_impl)]",
+        };
+
+        Some(format!(
+            "impl<T> core::ops::{tr}<{rhs}<T>> for {lhs}<T>
+where
+    T: crate::Num,
+{{
+    type Output = {output};
+
+    #[inline]{allow}
+    fn {method}(self, other: {rhs}<T>) -> {output} {{
+        {body}
+    }}
+}}
+",
+            tr = product.trait_name(),
+            method = product.method(),
+            lhs = lhs.name,
+            rhs = rhs.name,
+        ))
+    }
+
+    /// Generates product impls for all pairs of element types.
+    fn products(&self) -> String {
+        self.check();
+
+        let mut code = String::from("// Generated by build.rs from the algebra signature.\n");
+        for product in Product::ALL {
+            for lhs in self.elements {
+                for rhs in self.elements {
+                    if let Some(impl_) = self.product(product, lhs, rhs) {
+                        code.push('\n');
+                        code.push_str(&impl_);
+                    }
+                }
+            }
+        }
+        code
+    }
+
+    /// Generates element type definitions with linear operations,
+    /// involutions, norms and duals.
+    fn types(&self) -> String {
+        let mut code = String::from("// Generated by build.rs from the algebra signature.\n");
+        for element in self.elements {
+            code.push('\n');
+            code.push_str(&self.element(element));
+        }
+        code
+    }
+
+    /// Generates definition of the element type.
+    fn element(&self, element: &Element) -> String {
+        let name = element.name;
+        let grade = self.grade(element);
+        let fields: Vec<String> = element.blades.iter().map(|b| field(b)).collect();
+        let scalar = fields == ["0"];
+
+        let map = |f: &dyn Fn(&str) -> String| {
+            let fields: Vec<(String, String)> =
+                fields.iter().map(|name| (name.clone(), f(name))).collect();
+            construct(name, &fields)
+        };
+
+        let kind = match grade {
+            0 => "Scalar".to_owned(),
+            g if g as usize == self.basis.len() => "Pseudoscalar".to_owned(),
+            1 => "Vector".to_owned(),
+            2 => "Bivector".to_owned(),
+            3 => "Trivector".to_owned(),
+            g => format!("Grade {g} element"),
+        };
+
+        let (definition, params, new) = if scalar {
+            (
+                format!(
+                    "#[repr(transparent)]
+#[cfg_attr(feature = \"serde\", serde(transparent))]
+pub struct {name}<T>(pub T);"
+                ),
+                "s: T".to_owned(),
+                format!("{name}(s)"),
+            )
+        } else {
+            let members: Vec<String> = fields
+                .iter()
+                .map(|f| format!("/// Coefficient of `{f}`.\n    pub {f}: T,"))
+                .collect();
+            let params: Vec<String> = fields.iter().map(|f| format!("{f}: T")).collect();
+            (
+                format!(
+                    "#[repr(C)]\npub struct {name}<T> {{\n    {}\n}}",
+                    members.join("\n    ")
+                ),
+                params.join(", "),
+                map(&|f| f.to_owned()),
+            )
+        };
+
+        // Sign of the reverse, grade involution and Clifford conjugate.
+        let reverse = if grade % 4 < 2 { "" } else { "-" };
+        let involution = if grade % 2 == 1 { "-" } else { "" };
+        let conjugate = if (grade + 1) % 4 < 2 { "" } else { "-" };
+
+        // Scalar part of the element times its reverse.
+        let mut norm2 = String::new();
+        for (name, f) in element.blades.iter().zip(&fields) {
+            let (blade, _) = self.parse(name);
+            let (square, _) = self.multiply(blade, blade);
+            let sign = if reverse.is_empty() { square } else { -square };
+            let term = format!("self.{f} * self.{f}");
+            match (norm2.is_empty(), sign) {
+                (_, 0) => {}
+                (true, 1) => norm2 = term,
+                (true, _) => norm2 = format!("-{term}"),
+                (false, 1) => write!(norm2, " + {term}").unwrap(),
+                (false, _) => write!(norm2, " - {term}").unwrap(),
+            }
+        }
+        if norm2.is_empty() {
+            norm2 = "T::ZERO".to_owned();
+        }
+
+        // Right complement, so that element outer its dual is the pseudoscalar.
+        let full = Blade((1 << self.basis.len()) - 1);
+        let mut complements = Vec::new();
+        for name in element.blades {
+            let (blade, sign) = self.parse(name);
+            let complement = Blade(full.0 ^ blade.0);
+            let (s, r) = self.multiply(blade, complement);
+            assert!(s != 0 && r == full);
+            complements.push((sign * s, complement, field(name)));
+        }
+        let blades: Vec<Blade> = complements.iter().map(|c| c.1).collect();
+        let dual = self.covering(&blades);
+        let dual_fields: Vec<(String, String)> = dual
+            .blades
+            .iter()
+            .map(|name| {
+                let (blade, sign) = self.parse(name);
+                let expr = match complements.iter().find(|c| c.1 == blade) {
+                    Some((s, _, f)) if s * sign > 0 => format!("self.{f}"),
+                    Some((_, _, f)) => format!("-self.{f}"),
+                    None => "T::ZERO".to_owned(),
+                };
+                (field(name), expr)
+            })
+            .collect();
+        let dual_body = construct(dual.name, &dual_fields);
+        let dual = dual.name;
+
+        let zero = map(&|_| "T::ZERO".to_owned());
+        let add = map(&|f| format!("self.{f} + other.{f}"));
+        let sub = map(&|f| format!("self.{f} - other.{f}"));
+        let neg = map(&|f| format!("-self.{f}"));
+        let mul = map(&|f| format!("self.{f} * other"));
+        let div = map(&|f| format!("self.{f} / other"));
+        let sign = |s: &str| {
+            if s.is_empty() {
+                "self".to_owned()
+            } else {
+                "-self".to_owned()
+            }
+        };
+        let (reverse, involution, conjugate) = (sign(reverse), sign(involution), sign(conjugate));
+        let title = self.title;
+        let allow = if fields.len() > 7 {
+            "\n    #[allow(clippy::too_many_arguments)]"
+        } else {
+            ""
+        };
+
+        format!(
+            "/// {kind} of the {title}.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize))]
+{definition}
+
+impl<T> {name}<T> {{
+    /// Creates a new element from its components.{allow}
+    pub const fn new({params}) -> Self {{
+        {new}
+    }}
+}}
+
+impl<T> {name}<T>
+where
+    T: crate::Num,
+{{
+    /// Zero element.
+    pub const ZERO: Self = {zero};
+
+    /// Returns scalar part of the element times its reverse.
+    ///
+    /// May be negative in algebras with mixed signature.
+    pub fn norm2(&self) -> T {{
+        {norm2}
+    }}
+
+    /// Returns square root of the absolute value of [`norm2`](Self::norm2).
+    pub fn norm(&self) -> T {{
+        self.norm2().abs().sqrt()
+    }}
+
+    /// Returns the reverse of the element.
+    ///
+    /// Same as `!`.
+    #[inline]
+    pub fn reverse(self) -> Self {{
+        {reverse}
+    }}
+
+    /// Returns the grade involution of the element.
+    #[inline]
+    pub fn grade_involution(self) -> Self {{
+        {involution}
+    }}
+
+    /// Returns the Clifford conjugate of the element.
+    #[inline]
+    pub fn conjugate(self) -> Self {{
+        {conjugate}
+    }}
+}}
+
+impl<T> core::ops::Add for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: Self) -> Self {{
+        {add}
+    }}
+}}
+
+impl<T> core::ops::Sub for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, other: Self) -> Self {{
+        {sub}
+    }}
+}}
+
+impl<T> core::ops::Neg for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {{
+        {neg}
+    }}
+}}
+
+impl<T> core::ops::Not for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {{
+        self.reverse()
+    }}
+}}
+
+impl<T> core::ops::Mul<T> for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: T) -> Self {{
+        {mul}
+    }}
+}}
+
+impl<T> core::ops::Div<T> for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: T) -> Self {{
+        {div}
+    }}
+}}
+
+impl<T> crate::algebra::Dual for {name}<T>
+where
+    T: crate::Num,
+{{
+    type Output = {dual}<T>;
+
+    #[inline]
+    fn dual(self) -> {dual}<T> {{
+        {dual_body}
+    }}
+}}
+"
+        )
+    }
+}
+
+/// Returns field name storing the blade.
+fn field(blade: &str) -> String {
+    if blade.is_empty() {
+        "0".to_owned()
+    } else {
+        blade.to_owned()
+    }
+}
+
+/// Returns struct construction expression.
+fn construct(name: &str, fields: &[(String, String)]) -> String {
+    if let [(field, expr)] = fields {
+        if field == "0" {
+            return format!("{name}({expr})");
+        }
+    }
+
+    let fields: Vec<String> = fields
+        .iter()
+        .map(|(field, expr)| {
+            if field == expr {
+                field.clone()
+            } else {
+                format!("{field}: {expr}")
+            }
+        })
+        .collect();
+    format!("{name} {{ {} }}", fields.join(", "))
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let out = env::var("OUT_DIR").unwrap();
+    let out = Path::new(&out);
+
+    for algebra in [PGA2, PGA3] {
+        fs::write(out.join(algebra.file), algebra.products()).unwrap();
+    }
+
+    for algebra in [VGA3, STA, CGA3, EPGA3, HPGA3] {
+        let code = algebra.types() + &algebra.products();
+        fs::write(out.join(algebra.file), code).unwrap();
+    }
+}
@@ -154,5 +154,5 @@ fn camera(aspect: f32, fov: f32) -> Matrix4<f32> {
         [0.0, 0.5, 2.0, 1.0],
     ]);
 
-    translate * perspective
+    perspective * translate
 }
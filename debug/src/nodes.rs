@@ -597,13 +597,14 @@ impl Motor {
     fn move_object(&self, geom: Value) -> Value {
         match self {
             Motor::Motor2(motor) => match geom {
-                Value::Point2(point) => Value::Point2(motor.move_point(point)),
-                Value::Line2(line) => Value::Line2(motor.move_line(line)),
+                Value::Point2(point) => Value::Point2(motor.apply(point)),
+                Value::Line2(line) => Value::Line2(motor.apply(line)),
+                Value::Motor2(other) => Value::Motor2(motor.apply(other)),
                 _ => unreachable!(),
             },
             Motor::Motor3(motor) => match geom {
-                Value::Point3(point) => Value::Point3(motor.move_point(point)),
-                Value::Line3(line) => Value::Line3(motor.move_line(line)),
+                Value::Point3(point) => Value::Point3(motor.apply(point)),
+                Value::Line3(line) => Value::Line3(motor.apply(line)),
                 _ => unreachable!(),
             },
         }
@@ -0,0 +1,27 @@
+//! Conformal geometric algebra of the space, Cl(4,1).
+//!
+//! Basis vectors `e1`, `e2`, `e3` span the euclidean space,
+//! `e4` squares to 1 and `e5` squares to -1.
+//! Points at origin and infinity are `(e5 - e4) / 2` and `e4 + e5`.
+
+include!(concat!(env!("OUT_DIR"), "/cga3.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn null_basis() {
+        let origin = Vector::new(0.0, 0.0, 0.0, -0.5, 0.5);
+        let infinity = Vector::new(0.0, 0.0, 0.0, 1.0, 1.0);
+
+        assert_eq!(origin | origin, Scalar(0.0));
+        assert_eq!(infinity | infinity, Scalar(0.0));
+        assert_eq!(origin | infinity, Scalar(-1.0));
+
+        let e4 = Vector::new(0.0, 0.0, 0.0, 1.0, 0.0);
+        let e5 = Vector::new(0.0, 0.0, 0.0, 0.0, 1.0);
+        assert_eq!((e4 * e4).0, Scalar(1.0));
+        assert_eq!((e5 * e5).0, Scalar(-1.0));
+    }
+}
@@ -0,0 +1,6 @@
+//! Elliptic projective geometric algebra of the space, Cl(4,0,0).
+//!
+//! Same layout as the euclidean projective algebra,
+//! but `e0` squares to 1.
+
+include!(concat!(env!("OUT_DIR"), "/epga3.rs"));
@@ -0,0 +1,6 @@
+//! Hyperbolic projective geometric algebra of the space, Cl(3,1,0).
+//!
+//! Same layout as the euclidean projective algebra,
+//! but `e0` squares to -1.
+
+include!(concat!(env!("OUT_DIR"), "/hpga3.rs"));
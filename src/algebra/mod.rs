@@ -0,0 +1,19 @@
+//! Geometric algebras with signatures other than the euclidean projective one.
+//!
+//! Element types and their products are generated by the build script
+//! from the algebra signature, same as products of the projective elements.
+
+pub mod cga;
+pub mod epga;
+pub mod hpga;
+pub mod sta;
+pub mod vga;
+
+/// A trait for duality operations.
+pub trait Dual {
+    /// Dual type of the element.
+    type Output;
+
+    /// Returns the dual of the element.
+    fn dual(self) -> Self::Output;
+}
@@ -0,0 +1,27 @@
+//! Spacetime algebra, Cl(1,3).
+//!
+//! Time basis vector `e0` squares to 1 and space basis vectors `e1`, `e2`, `e3` square to -1.
+//! Lorentz transformations are even elements applied with the sandwich product.
+
+include!(concat!(env!("OUT_DIR"), "/sta.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature() {
+        let t = Vector::new(1.0, 0.0, 0.0, 0.0);
+        let x = Vector::new(0.0, 1.0, 0.0, 0.0);
+        assert_eq!(t * t, (Scalar(1.0), BiVector::ZERO));
+        assert_eq!(x * x, (Scalar(-1.0), BiVector::ZERO));
+
+        // Boost generator squares to 1, rotation generator to -1.
+        let (s, _, _) = BiVector::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0)
+            * BiVector::new(1.0, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(s, Scalar(1.0));
+        let (s, _, _) = BiVector::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+            * BiVector::new(0.0, 0.0, 0.0, 1.0, 0.0, 0.0);
+        assert_eq!(s, Scalar(-1.0));
+    }
+}
@@ -0,0 +1,25 @@
+//! Vanilla geometric algebra of the space, Cl(3,0,0).
+//!
+//! Rotors are even elements `Scalar + BiVector`
+//! and rotate vectors with the sandwich product `R * v * !R`.
+
+include!(concat!(env!("OUT_DIR"), "/vga3.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algebra::Dual;
+
+    #[test]
+    fn basis_products() {
+        let (e1, e2) = (Vector::new(1.0, 0.0, 0.0), Vector::new(0.0, 1.0, 0.0));
+        assert_eq!(e1 * e1, (Scalar(1.0), BiVector::ZERO));
+        assert_eq!(e1 * e2, (Scalar(0.0), BiVector::new(1.0, 0.0, 0.0)));
+        assert_eq!(e2 * e1, (Scalar(0.0), BiVector::new(-1.0, 0.0, 0.0)));
+
+        let e12 = BiVector::new(1.0, 0.0, 0.0);
+        assert_eq!(e12 * e12, (Scalar(-1.0), BiVector::ZERO));
+        assert_eq!(e1 * Pseudo::new(1.0), BiVector::new(0.0, 0.0, 1.0));
+        assert_eq!(e1.dual(), BiVector::new(0.0, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{
+    algebra::cga::{BiVector, Vector},
+    Line3, Num, Plane3, Point3, Vector3,
+};
+
+use super::{down, dual, infinity, undual, up, PointPair3, Sphere3};
+
+/// Circle in 3d conformal geometric algebra.
+///
+/// Lines are flat circles.
+/// Circles with negative squared radius are imaginary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Circle3<T>(BiVector<T>);
+
+impl<T> Circle3<T>
+where
+    T: Num,
+{
+    pub(super) const fn bivector(&self) -> BiVector<T> {
+        self.0
+    }
+
+    pub(super) const fn from_bivector(bivector: BiVector<T>) -> Self {
+        Circle3(bivector)
+    }
+
+    /// Creates a circle with the given center, normal of its plane and radius.
+    ///
+    /// The center must be finite, an ideal one gives a degenerate circle.
+    pub fn new(center: Point3<T>, normal: Vector3<T>, radius: T) -> Self {
+        let plane = Sphere3::from(Plane3::from_point_normal(center, normal));
+        Sphere3::new(center, radius).meet(plane)
+    }
+
+    /// Creates a circle through three points.
+    ///
+    /// Returns flat circle if points are collinear or one of them is ideal.
+    pub fn through(a: Point3<T>, b: Point3<T>, c: Point3<T>) -> Self {
+        PointPair3::new(a, b).join(c)
+    }
+
+    /// Returns true if the circle is a line.
+    pub fn is_flat(&self) -> bool {
+        (infinity() | self.0) == Vector::ZERO
+    }
+
+    /// Returns center of the circle.
+    ///
+    /// Returns ideal point for flat circles.
+    pub fn center(&self) -> Point3<T> {
+        let (v, t) = self.0 * infinity();
+        down((v * self.0).0 + (t * self.0).0)
+    }
+
+    /// Returns squared radius of the circle.
+    ///
+    /// Negative for imaginary circles.
+    pub fn radius2(&self) -> T {
+        self.0.norm2() / (infinity() | self.0).norm2()
+    }
+
+    /// Returns radius of the circle.
+    pub fn radius(&self) -> T {
+        self.radius2().abs().sqrt()
+    }
+
+    /// Returns the line if the circle is flat.
+    pub fn line(&self) -> Option<Line3<T>> {
+        if self.is_flat() {
+            let b = self.0;
+            Some(Line3::new(b.e14, b.e24, b.e34, b.e12, -b.e13, b.e23))
+        } else {
+            None
+        }
+    }
+
+    /// Finds the pair of points where the circle intersects the sphere.
+    ///
+    /// Returns imaginary point pair if they do not intersect.
+    pub fn meet(&self, sphere: Sphere3<T>) -> PointPair3<T> {
+        PointPair3::from_trivector(self.0 ^ sphere.vector())
+    }
+
+    /// Finds the sphere through the circle and the point.
+    ///
+    /// Returns flat sphere, which is a plane, if the point is ideal.
+    pub fn join(&self, point: Point3<T>) -> Sphere3<T> {
+        Sphere3::from_vector(undual(dual(self.0) ^ up(point)))
+    }
+}
+
+impl<T> From<Line3<T>> for Circle3<T>
+where
+    T: Num,
+{
+    /// Converts line to the flat circle.
+    fn from(line: Line3<T>) -> Self {
+        let l = line.bivector();
+        Circle3(BiVector::new(
+            l.e12,
+            -l.e31,
+            l.e01,
+            l.e01,
+            l.e23,
+            l.e02,
+            l.e02,
+            l.e03,
+            l.e03,
+            T::ZERO,
+        ))
+    }
+}
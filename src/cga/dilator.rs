@@ -0,0 +1,78 @@
+use crate::{algebra::cga::BiVector, Num, Point3};
+
+use super::{down, infinity, up, Circle3, Outermorphism, PointPair3, Sphere3};
+
+/// Uniform scaling about a center point in 3d conformal geometric algebra.
+///
+/// Stored as an even versor and applied with the sandwich product.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dilator3<T> {
+    s: T,
+    b: BiVector<T>,
+}
+
+impl<T> Dilator3<T>
+where
+    T: Num,
+{
+    /// Dilator that does not scale.
+    pub const IDENTITY: Self = Dilator3 {
+        s: T::ONE,
+        b: BiVector::ZERO,
+    };
+
+    /// Creates a dilator scaling about the center by positive factor.
+    ///
+    /// The center must be finite, an ideal one gives a dilator that does not move anything.
+    pub fn new(center: Point3<T>, scale: T) -> Self {
+        let e = up(center) ^ infinity();
+        let norm = (scale.sqrt() * T::TWO).recip();
+        Dilator3 {
+            s: (scale + T::ONE) * norm,
+            b: e * ((scale - T::ONE) * norm),
+        }
+    }
+
+    /// Returns dilator that undoes this one.
+    pub fn inverse(&self) -> Self {
+        Dilator3 {
+            s: self.s,
+            b: -self.b,
+        }
+    }
+
+    /// Returns the dilator as a linear map.
+    fn sandwich(&self) -> Outermorphism<T> {
+        let (s, b) = (self.s, self.b);
+        Outermorphism::new(|x| {
+            // (s + b) * x * (s - b)
+            let (bv, bt) = b * x;
+            let (xv, _) = x * b;
+            let bxb = (bv * b).0 + (bt * b).0;
+            x * (s * s) + (bv - xv) * s - bxb
+        })
+    }
+
+    /// Moves the point by this dilator.
+    ///
+    /// The point must be finite.
+    pub fn move_point(&self, point: Point3<T>) -> Point3<T> {
+        down(self.sandwich().vector(up(point)))
+    }
+
+    /// Moves the sphere by this dilator.
+    pub fn move_sphere(&self, sphere: Sphere3<T>) -> Sphere3<T> {
+        Sphere3::from_vector(self.sandwich().vector(sphere.vector()))
+    }
+
+    /// Moves the circle by this dilator.
+    pub fn move_circle(&self, circle: Circle3<T>) -> Circle3<T> {
+        Circle3::from_bivector(self.sandwich().bivector(circle.bivector()))
+    }
+
+    /// Moves the point pair by this dilator.
+    pub fn move_point_pair(&self, pair: PointPair3<T>) -> PointPair3<T> {
+        PointPair3::from_trivector(self.sandwich().trivector(pair.trivector()))
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{algebra::cga::TriVector, Num, Point3};
+
+/// Flat point in 3d conformal geometric algebra.
+///
+/// Pair of the finite point and the point at infinity.
+/// Same as [`Point3`] in projective geometric algebra.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct FlatPoint3<T>(TriVector<T>);
+
+impl<T> FlatPoint3<T>
+where
+    T: Num,
+{
+    pub(super) const fn trivector(&self) -> TriVector<T> {
+        self.0
+    }
+
+    pub(super) const fn from_trivector(trivector: TriVector<T>) -> Self {
+        FlatPoint3(trivector)
+    }
+
+    /// Returns the point.
+    pub fn point(&self) -> Point3<T> {
+        let t = self.0;
+        Point3::new(t.e123, t.e234, -t.e134, t.e124)
+    }
+}
+
+impl<T> From<Point3<T>> for FlatPoint3<T>
+where
+    T: Num,
+{
+    fn from(point: Point3<T>) -> Self {
+        let p = point.trivector();
+        FlatPoint3(TriVector::new(
+            p.e123,
+            p.e021,
+            p.e021,
+            -p.e013,
+            -p.e013,
+            T::ZERO,
+            p.e032,
+            p.e032,
+            T::ZERO,
+            T::ZERO,
+        ))
+    }
+}
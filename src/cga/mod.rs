@@ -0,0 +1,297 @@
+//! Round objects of the conformal geometric algebra of the space.
+//!
+//! All objects are stored in the dual form,
+//! so point `x` lies on the object `a` when `x | a` is zero
+//! and meet of objects is their outer product.
+
+mod circle;
+mod dilator;
+mod flat_point;
+mod point_pair;
+mod sphere;
+
+use core::ops::{Mul, Neg};
+
+use crate::{
+    algebra::cga::{BiVector, Pseudo, TriVector, Vector},
+    Num, Point3,
+};
+
+pub use self::{
+    circle::Circle3, dilator::Dilator3, flat_point::FlatPoint3, point_pair::PointPair3,
+    sphere::Sphere3,
+};
+
+/// Returns the point at infinity.
+fn infinity<T>() -> Vector<T>
+where
+    T: Num,
+{
+    Vector::new(T::ZERO, T::ZERO, T::ZERO, T::ONE, T::ONE)
+}
+
+/// Embeds point into the conformal space.
+///
+/// Finite points get unit weight.
+/// All ideal points map to the single point at infinity.
+fn up<T>(point: Point3<T>) -> Vector<T>
+where
+    T: Num,
+{
+    let p = point.trivector();
+    if p.e123 == T::ZERO {
+        return infinity();
+    }
+
+    let w = p.e123.recip();
+    let (x, y, z) = (p.e032 * w, p.e013 * w, p.e021 * w);
+    let h = (x * x + y * y + z * z) * T::HALF;
+    Vector::new(x, y, z, h - T::HALF, h + T::HALF)
+}
+
+/// Projects conformal vector back to the euclidean point.
+///
+/// Returns ideal point if the vector has zero weight.
+fn down<T>(v: Vector<T>) -> Point3<T>
+where
+    T: Num,
+{
+    let w = v.e5 - v.e4;
+    if w == T::ZERO {
+        Point3::ideal(v.e1, v.e2, v.e3)
+    } else {
+        Point3::at(v.e1 / w, v.e2 / w, v.e3 / w)
+    }
+}
+
+/// Converts object from the dual to the direct form.
+fn dual<T, X>(x: X) -> X::Output
+where
+    T: Num,
+    X: Mul<Pseudo<T>>,
+{
+    x * Pseudo::new(T::ONE)
+}
+
+/// Converts object from the direct to the dual form.
+fn undual<T, X>(x: X) -> X::Output
+where
+    T: Num,
+    X: Mul<Pseudo<T>>,
+    X::Output: Neg<Output = X::Output>,
+{
+    -(x * Pseudo::new(T::ONE))
+}
+
+/// Linear map of vectors extended to other grades as outermorphism.
+///
+/// Versors act on all objects this way.
+struct Outermorphism<T>([Vector<T>; 5]);
+
+impl<T> Outermorphism<T>
+where
+    T: Num,
+{
+    fn new(f: impl Fn(Vector<T>) -> Vector<T>) -> Self {
+        let (o, l) = (T::ZERO, T::ONE);
+        Outermorphism([
+            f(Vector::new(l, o, o, o, o)),
+            f(Vector::new(o, l, o, o, o)),
+            f(Vector::new(o, o, l, o, o)),
+            f(Vector::new(o, o, o, l, o)),
+            f(Vector::new(o, o, o, o, l)),
+        ])
+    }
+
+    fn vector(&self, v: Vector<T>) -> Vector<T> {
+        let [f1, f2, f3, f4, f5] = self.0;
+        f1 * v.e1 + f2 * v.e2 + f3 * v.e3 + f4 * v.e4 + f5 * v.e5
+    }
+
+    fn bivector(&self, b: BiVector<T>) -> BiVector<T> {
+        let [f1, f2, f3, f4, f5] = self.0;
+        (f1 ^ f2) * b.e12
+            + (f1 ^ f3) * b.e13
+            + (f1 ^ f4) * b.e14
+            + (f1 ^ f5) * b.e15
+            + (f2 ^ f3) * b.e23
+            + (f2 ^ f4) * b.e24
+            + (f2 ^ f5) * b.e25
+            + (f3 ^ f4) * b.e34
+            + (f3 ^ f5) * b.e35
+            + (f4 ^ f5) * b.e45
+    }
+
+    fn trivector(&self, t: TriVector<T>) -> TriVector<T> {
+        let [f1, f2, f3, f4, f5] = self.0;
+        (f1 ^ f2 ^ f3) * t.e123
+            + (f1 ^ f2 ^ f4) * t.e124
+            + (f1 ^ f2 ^ f5) * t.e125
+            + (f1 ^ f3 ^ f4) * t.e134
+            + (f1 ^ f3 ^ f5) * t.e135
+            + (f1 ^ f4 ^ f5) * t.e145
+            + (f2 ^ f3 ^ f4) * t.e234
+            + (f2 ^ f3 ^ f5) * t.e235
+            + (f2 ^ f4 ^ f5) * t.e245
+            + (f3 ^ f4 ^ f5) * t.e345
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Plane3, Vector3};
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    fn assert_point(p: Point3<f64>, expected: [f64; 3]) {
+        let (x, y, z) = p.normalized().coords();
+        assert_close(x, expected[0]);
+        assert_close(y, expected[1]);
+        assert_close(z, expected[2]);
+    }
+
+    #[test]
+    fn sphere_through_points() {
+        let sphere = Sphere3::through(
+            Point3::at(3.0, 2.0, 3.0),
+            Point3::at(1.0, 4.0, 3.0),
+            Point3::at(1.0, 2.0, 5.0),
+            Point3::at(-1.0, 2.0, 3.0),
+        );
+        assert!(!sphere.is_flat() && !sphere.is_imaginary());
+        assert_point(sphere.center(), [1.0, 2.0, 3.0]);
+        assert_close(sphere.radius(), 2.0);
+
+        let sphere = Sphere3::new(Point3::at(1.0, 2.0, 3.0), 2.0);
+        assert!(sphere.power(Point3::at(1.0, 2.0, 3.5)) > 0.0);
+        assert_close(sphere.power(Point3::at(1.0, 0.0, 3.0)), 0.0);
+        assert!(sphere.power(Point3::at(5.0, 2.0, 3.0)) < 0.0);
+
+        assert_close(
+            Sphere3::imaginary(Point3::at(0.0, 0.0, 0.0), 2.0).radius2(),
+            -4.0,
+        );
+    }
+
+    #[test]
+    fn sphere_inversion() {
+        let unit = Sphere3::new(Point3::at(0.0, 0.0, 0.0), 1.0);
+        assert_point(
+            unit.invert_point(Point3::at(2.0, 0.0, 0.0)),
+            [0.5, 0.0, 0.0],
+        );
+
+        let plane = Sphere3::from(Plane3::new(-1.0, 1.0, 0.0, 0.0));
+        assert!(plane.is_flat());
+        assert_point(
+            plane.invert_point(Point3::at(3.0, 1.0, 0.0)),
+            [-1.0, 1.0, 0.0],
+        );
+    }
+
+    #[test]
+    fn circles() {
+        let circle = Circle3::new(Point3::at(0.0, 0.0, 1.0), Vector3::new(0.0, 0.0, 1.0), 3.0);
+        assert_point(circle.center(), [0.0, 0.0, 1.0]);
+        assert_close(circle.radius(), 3.0);
+
+        let circle = Circle3::through(
+            Point3::at(1.0, 0.0, 0.0),
+            Point3::at(0.0, 1.0, 0.0),
+            Point3::at(-1.0, 0.0, 0.0),
+        );
+        assert_point(circle.center(), [0.0, 0.0, 0.0]);
+        assert_close(circle.radius(), 1.0);
+
+        let a = Sphere3::new(Point3::at(0.0, 0.0, 0.0), 2.0);
+        let b = Sphere3::new(Point3::at(2.0, 0.0, 0.0), 2.0);
+        let circle = a.meet(b);
+        assert_point(circle.center(), [1.0, 0.0, 0.0]);
+        assert_close(circle.radius2(), 3.0);
+
+        let line = Circle3::through(
+            Point3::at(0.0, 0.0, 0.0),
+            Point3::at(1.0, 0.0, 0.0),
+            Point3::at(2.0, 0.0, 0.0),
+        );
+        assert!(line.is_flat());
+        let line = line.line().unwrap();
+        let d = line.normalized().direction();
+        assert_close(d.x.abs(), 1.0);
+        assert_close(line.closest_point_to_origin().coords().1, 0.0);
+    }
+
+    #[test]
+    fn ideal_points_go_to_infinity() {
+        let ideal = Point3::ideal(1.0, 1.0, 0.0);
+
+        let pair = PointPair3::new(Point3::at(1.0, 2.0, 3.0), ideal);
+        assert!(pair.is_flat());
+        assert_point(pair.flat_point().unwrap().point(), [1.0, 2.0, 3.0]);
+
+        let line = Circle3::through(Point3::at(0.0, 0.0, 1.0), Point3::at(2.0, 0.0, 1.0), ideal);
+        assert!(line.is_flat());
+        let d = line.line().unwrap().normalized().direction();
+        assert_close(d.x.abs(), 1.0);
+
+        let plane = Sphere3::through(
+            Point3::at(0.0, 0.0, 2.0),
+            Point3::at(1.0, 0.0, 2.0),
+            Point3::at(0.0, 1.0, 2.0),
+            ideal,
+        );
+        assert!(plane.is_flat());
+        assert_close(plane.power(Point3::at(5.0, -3.0, 2.0)), 0.0);
+
+        let unit = Sphere3::new(Point3::at(1.0, 0.0, 0.0), 1.0);
+        assert_point(unit.invert_point(ideal), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn point_pairs() {
+        let (a, b) = (Point3::at(1.0, 2.0, 3.0), Point3::at(-1.0, 0.0, 2.0));
+        let (p, q) = PointPair3::new(a, b).points().unwrap();
+        assert_point(p, [1.0, 2.0, 3.0]);
+        assert_point(q, [-1.0, 0.0, 2.0]);
+
+        let circle = Circle3::new(Point3::at(0.0, 0.0, 0.0), Vector3::new(0.0, 0.0, 1.0), 2.0);
+        let pair = circle.meet(Sphere3::new(Point3::at(2.0, 0.0, 0.0), 2.0));
+        let (p, q) = pair.points().unwrap();
+        let (p, q) = (p.normalized().coords(), q.normalized().coords());
+        assert_close(p.0, 1.0);
+        assert_close(q.0, 1.0);
+        assert_close(p.1.abs(), 3.0f64.sqrt());
+        assert_close(p.1 + q.1, 0.0);
+
+        let far = circle.meet(Sphere3::new(Point3::at(10.0, 0.0, 0.0), 1.0));
+        assert!(far.is_imaginary());
+        assert!(far.points().is_none());
+    }
+
+    #[test]
+    fn dilator_scales_about_center() {
+        let d = Dilator3::new(Point3::at(1.0, 1.0, 1.0), 2.0);
+        assert_point(d.move_point(Point3::at(2.0, 1.0, 1.0)), [3.0, 1.0, 1.0]);
+        assert_point(
+            d.inverse().move_point(Point3::at(3.0, 1.0, 1.0)),
+            [2.0, 1.0, 1.0],
+        );
+
+        let sphere = d.move_sphere(Sphere3::new(Point3::at(1.0, 2.0, 1.0), 0.5));
+        assert_point(sphere.center(), [1.0, 3.0, 1.0]);
+        assert_close(sphere.radius(), 1.0);
+
+        let circle = Circle3::new(Point3::at(1.0, 1.0, 2.0), Vector3::new(0.0, 0.0, 1.0), 1.0);
+        let circle = d.move_circle(circle);
+        assert_point(circle.center(), [1.0, 1.0, 3.0]);
+        assert_close(circle.radius(), 2.0);
+
+        assert_point(
+            Dilator3::IDENTITY.move_point(Point3::at(4.0, 5.0, 6.0)),
+            [4.0, 5.0, 6.0],
+        );
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{
+    algebra::cga::{BiVector, TriVector},
+    Num, Point3,
+};
+
+use super::{down, dual, infinity, undual, up, Circle3, FlatPoint3};
+
+/// Pair of points in 3d conformal geometric algebra.
+///
+/// Flat points are point pairs with one point at infinity.
+/// Point pairs with negative squared radius are imaginary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct PointPair3<T>(TriVector<T>);
+
+impl<T> PointPair3<T>
+where
+    T: Num,
+{
+    pub(super) const fn trivector(&self) -> TriVector<T> {
+        self.0
+    }
+
+    pub(super) const fn from_trivector(trivector: TriVector<T>) -> Self {
+        PointPair3(trivector)
+    }
+
+    /// Creates a pair of two points.
+    ///
+    /// The pair is flat if one of the points is ideal.
+    pub fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        PointPair3(undual(up(a) ^ up(b)))
+    }
+
+    /// Returns true if one of the points is at infinity.
+    pub fn is_flat(&self) -> bool {
+        (infinity() | self.0) == BiVector::ZERO
+    }
+
+    /// Returns true if the point pair has negative squared radius.
+    pub fn is_imaginary(&self) -> bool {
+        self.0.norm2() < T::ZERO
+    }
+
+    /// Returns the points of the pair.
+    ///
+    /// Points are returned in the order they were given to [`PointPair3::new`].
+    /// Returns `None` for flat and imaginary point pairs.
+    pub fn points(&self) -> Option<(Point3<T>, Point3<T>)> {
+        if self.is_flat() || self.is_imaginary() {
+            return None;
+        }
+
+        let t = dual(self.0);
+        let root = (-t.norm2()).sqrt();
+        let n = infinity() | t;
+        let p = (t * n).0;
+        Some((down(p - n * root), down(p + n * root)))
+    }
+
+    /// Returns the flat point if the point pair is flat.
+    pub fn flat_point(&self) -> Option<FlatPoint3<T>> {
+        if self.is_flat() {
+            Some(FlatPoint3::from_trivector(self.0))
+        } else {
+            None
+        }
+    }
+
+    /// Finds the circle through the point pair and the point.
+    ///
+    /// Returns flat circle, which is a line, if the point is ideal.
+    pub fn join(&self, point: Point3<T>) -> Circle3<T> {
+        Circle3::from_bivector(undual(dual(self.0) ^ up(point)))
+    }
+}
+
+impl<T> From<FlatPoint3<T>> for PointPair3<T>
+where
+    T: Num,
+{
+    /// Converts flat point to the point pair with one point at infinity.
+    fn from(point: FlatPoint3<T>) -> Self {
+        PointPair3(point.trivector())
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{algebra::cga::Vector, Num, Plane3, Point3};
+
+use super::{down, infinity, up, Circle3, Outermorphism, PointPair3};
+
+/// Sphere in 3d conformal geometric algebra.
+///
+/// Points are spheres of zero radius and planes are flat spheres.
+/// Spheres with negative squared radius are imaginary.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Sphere3<T>(Vector<T>);
+
+impl<T> Sphere3<T>
+where
+    T: Num,
+{
+    pub(super) const fn vector(&self) -> Vector<T> {
+        self.0
+    }
+
+    pub(super) const fn from_vector(vector: Vector<T>) -> Self {
+        Sphere3(vector)
+    }
+
+    /// Creates a sphere with the given center and radius.
+    ///
+    /// The center must be finite, an ideal one gives a degenerate sphere.
+    pub fn new(center: Point3<T>, radius: T) -> Self {
+        Sphere3(up(center) - infinity() * (radius * radius * T::HALF))
+    }
+
+    /// Creates an imaginary sphere with the given center and radius.
+    ///
+    /// The center must be finite, an ideal one gives a degenerate sphere.
+    pub fn imaginary(center: Point3<T>, radius: T) -> Self {
+        Sphere3(up(center) + infinity() * (radius * radius * T::HALF))
+    }
+
+    /// Creates a sphere through four points.
+    ///
+    /// Returns flat sphere if points are coplanar or one of them is ideal.
+    pub fn through(a: Point3<T>, b: Point3<T>, c: Point3<T>, d: Point3<T>) -> Self {
+        Circle3::through(a, b, c).join(d)
+    }
+
+    /// Returns weight of the sphere.
+    ///
+    /// Zero for flat spheres.
+    fn weight(&self) -> T {
+        self.0.e5 - self.0.e4
+    }
+
+    /// Returns true if the sphere is a plane.
+    pub fn is_flat(&self) -> bool {
+        self.weight() == T::ZERO
+    }
+
+    /// Returns true if the sphere has negative squared radius.
+    pub fn is_imaginary(&self) -> bool {
+        self.0.norm2() < T::ZERO
+    }
+
+    /// Returns center of the sphere.
+    ///
+    /// Returns ideal point along the normal for flat spheres.
+    pub fn center(&self) -> Point3<T> {
+        down(self.0)
+    }
+
+    /// Returns squared radius of the sphere.
+    ///
+    /// Negative for imaginary spheres.
+    pub fn radius2(&self) -> T {
+        let w = self.weight();
+        self.0.norm2() / (w * w)
+    }
+
+    /// Returns radius of the sphere.
+    pub fn radius(&self) -> T {
+        self.radius2().abs().sqrt()
+    }
+
+    /// Returns the plane if the sphere is flat.
+    pub fn plane(&self) -> Option<Plane3<T>> {
+        if self.is_flat() {
+            Some(Plane3::new(-self.0.e4, self.0.e1, self.0.e2, self.0.e3))
+        } else {
+            None
+        }
+    }
+
+    /// Returns value that is positive for points inside the sphere,
+    /// negative outside and zero on the sphere.
+    ///
+    /// For flat spheres this is positive on the side the normal points to.
+    /// The point must be finite.
+    pub fn power(&self, point: Point3<T>) -> T {
+        let w = self.weight();
+        let w = if w == T::ZERO { T::ONE } else { w };
+        (up(point) | self.0).0 / w
+    }
+
+    /// Finds the circle where two spheres intersect.
+    ///
+    /// Returns imaginary circle if spheres do not intersect.
+    pub fn meet(&self, other: Sphere3<T>) -> Circle3<T> {
+        Circle3::from_bivector(self.0 ^ other.0)
+    }
+
+    /// Finds the pair of points where the sphere intersects the circle.
+    pub fn meet_circle(&self, circle: Circle3<T>) -> PointPair3<T> {
+        PointPair3::from_trivector(self.0 ^ circle.bivector())
+    }
+
+    /// Returns inversion in this sphere as a linear map.
+    ///
+    /// Sphere reflects all objects with the sandwich product `-s * x * s⁻¹`,
+    /// so it must not have zero radius.
+    fn inversion(&self) -> Outermorphism<T> {
+        let s = self.0;
+        let s2 = s.norm2();
+        Outermorphism::new(|x| {
+            let (a, b) = s * x;
+            -(a * s + (b * s).0) / s2
+        })
+    }
+
+    /// Inverts the point in this sphere.
+    ///
+    /// For flat spheres this is reflection in the plane.
+    /// Center of the sphere is mapped to ideal point and ideal points to the center.
+    pub fn invert_point(&self, point: Point3<T>) -> Point3<T> {
+        down(self.inversion().vector(up(point)))
+    }
+
+    /// Inverts the sphere in this sphere.
+    pub fn invert_sphere(&self, sphere: Sphere3<T>) -> Sphere3<T> {
+        Sphere3(self.inversion().vector(sphere.0))
+    }
+
+    /// Inverts the circle in this sphere.
+    pub fn invert_circle(&self, circle: Circle3<T>) -> Circle3<T> {
+        Circle3::from_bivector(self.inversion().bivector(circle.bivector()))
+    }
+
+    /// Inverts the point pair in this sphere.
+    pub fn invert_point_pair(&self, pair: PointPair3<T>) -> PointPair3<T> {
+        PointPair3::from_trivector(self.inversion().trivector(pair.trivector()))
+    }
+}
+
+impl<T> From<Point3<T>> for Sphere3<T>
+where
+    T: Num,
+{
+    /// Converts finite point to the sphere of zero radius.
+    ///
+    /// Ideal points convert to the point at infinity.
+    fn from(point: Point3<T>) -> Self {
+        Sphere3(up(point))
+    }
+}
+
+impl<T> From<Plane3<T>> for Sphere3<T>
+where
+    T: Num,
+{
+    /// Converts plane to the flat sphere.
+    fn from(plane: Plane3<T>) -> Self {
+        let v = plane.vector();
+        Sphere3(Vector::new(v.e1, v.e2, v.e3, -v.e0, -v.e0))
+    }
+}
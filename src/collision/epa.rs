@@ -0,0 +1,301 @@
+use crate::Num;
+
+use super::{gjk::Simplex, Contact, Gjk, SupportMap, Vertex, V3};
+
+/// Maximum number of polytope vertices.
+const MAX_VERTICES: usize = 64;
+
+/// Maximum number of polytope faces.
+const MAX_FACES: usize = 2 * MAX_VERTICES;
+
+/// Triangular face of the polytope with outward normal.
+#[derive(Clone, Copy, Debug)]
+struct Face<T> {
+    indices: [usize; 3],
+    normal: V3<T>,
+    distance: T,
+    valid: bool,
+}
+
+/// Allocation-free polytope expanded by EPA.
+struct Polytope<T> {
+    vertices: [Vertex<T>; MAX_VERTICES],
+    vertex_count: usize,
+    faces: [Face<T>; MAX_FACES],
+    face_count: usize,
+}
+
+impl<T> Polytope<T>
+where
+    T: Num,
+{
+    fn face(&self, indices: [usize; 3]) -> Face<T> {
+        let [a, b, c] = indices.map(|i| self.vertices[i].w);
+        let n = (b - a).cross(c - a);
+        let norm2 = n.norm2();
+
+        if norm2 == T::ZERO {
+            return Face {
+                indices,
+                normal: V3::ZERO,
+                distance: T::ZERO,
+                valid: false,
+            };
+        }
+
+        let normal = n * norm2.sqrt().recip();
+        Face {
+            indices,
+            normal,
+            distance: normal.dot(a),
+            valid: true,
+        }
+    }
+
+    fn push_face(&mut self, indices: [usize; 3]) -> bool {
+        if self.face_count == MAX_FACES {
+            return false;
+        }
+        self.faces[self.face_count] = self.face(indices);
+        self.face_count += 1;
+        true
+    }
+
+    fn closest_face(&self) -> Option<Face<T>> {
+        let mut best: Option<Face<T>> = None;
+        for face in &self.faces[..self.face_count] {
+            if face.valid && best.is_none_or(|best| face.distance < best.distance) {
+                best = Some(*face);
+            }
+        }
+        best
+    }
+
+    /// Adds the vertex and rebuilds faces visible from it.
+    fn expand(&mut self, vertex: Vertex<T>) -> bool {
+        if self.vertex_count == MAX_VERTICES {
+            return false;
+        }
+
+        let index = self.vertex_count;
+        self.vertices[index] = vertex;
+        self.vertex_count += 1;
+
+        let mut edges = [[0usize; 2]; MAX_FACES];
+        let mut edge_count = 0;
+
+        let mut i = 0;
+        while i < self.face_count {
+            let face = self.faces[i];
+            let a = self.vertices[face.indices[0]].w;
+
+            if face.valid && face.normal.dot(vertex.w - a) <= T::ZERO {
+                i += 1;
+                continue;
+            }
+
+            // Face is visible, remove it and keep its edges
+            // that are not shared with other removed faces.
+            let [p, q, r] = face.indices;
+            for edge in [[p, q], [q, r], [r, p]] {
+                let reversed = [edge[1], edge[0]];
+                match edges[..edge_count].iter().position(|e| *e == reversed) {
+                    Some(pos) => {
+                        edge_count -= 1;
+                        edges[pos] = edges[edge_count];
+                    }
+                    None => {
+                        if edge_count == MAX_FACES {
+                            return false;
+                        }
+                        edges[edge_count] = edge;
+                        edge_count += 1;
+                    }
+                }
+            }
+
+            self.face_count -= 1;
+            self.faces[i] = self.faces[self.face_count];
+        }
+
+        for edge in &edges[..edge_count] {
+            if !self.push_face([edge[0], edge[1], index]) {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Grows degenerate simplex into a tetrahedron when possible.
+fn complete<T, A, B>(a: &A, b: &B, simplex: &mut Simplex<T>, eps: T)
+where
+    T: Num,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let axes = [
+        V3::new(T::ONE, T::ZERO, T::ZERO),
+        V3::new(T::ZERO, T::ONE, T::ZERO),
+        V3::new(T::ZERO, T::ZERO, T::ONE),
+    ];
+
+    if simplex.len == 1 {
+        let p = simplex.vertices[0].w;
+        for dir in axes.iter().flat_map(|&axis| [axis, -axis]) {
+            let vertex = Vertex::support(a, b, dir);
+            if (vertex.w - p).norm2() > eps * eps {
+                simplex.push(vertex);
+                break;
+            }
+        }
+    }
+
+    if simplex.len == 2 {
+        let p = simplex.vertices[0].w;
+        let d = simplex.vertices[1].w - p;
+        for dir in axes.iter().map(|&axis| d.cross(axis)) {
+            for dir in [dir, -dir] {
+                let vertex = Vertex::support(a, b, dir);
+                if d.cross(vertex.w - p).norm2() > eps * eps * d.norm2() {
+                    simplex.push(vertex);
+                    break;
+                }
+            }
+            if simplex.len == 3 {
+                break;
+            }
+        }
+    }
+
+    if simplex.len == 3 {
+        let p = simplex.vertices[0].w;
+        let n = (simplex.vertices[1].w - p).cross(simplex.vertices[2].w - p);
+        for dir in [n, -n] {
+            let vertex = Vertex::support(a, b, dir);
+            let h = n.dot(vertex.w - p);
+            if h * h > eps * eps * n.norm2() {
+                simplex.push(vertex);
+                break;
+            }
+        }
+    }
+}
+
+/// Builds contact from the point on the polytope face closest to the origin.
+fn contact<T>(polytope: &Polytope<T>, face: &Face<T>) -> Contact<T>
+where
+    T: Num,
+{
+    let [va, vb, vc] = face.indices.map(|i| polytope.vertices[i]);
+    let p = face.normal * face.distance;
+
+    // Barycentric coordinates of the projection of the origin.
+    let v0 = vb.w - va.w;
+    let v1 = vc.w - va.w;
+    let v2 = p - va.w;
+    let d00 = v0.dot(v0);
+    let d01 = v0.dot(v1);
+    let d11 = v1.dot(v1);
+    let d20 = v2.dot(v0);
+    let d21 = v2.dot(v1);
+    let denom = d00 * d11 - d01 * d01;
+
+    let (u, v, w) = if denom == T::ZERO {
+        (T::ONE, T::ZERO, T::ZERO)
+    } else {
+        let v = (d11 * d20 - d01 * d21) / denom;
+        let w = (d00 * d21 - d01 * d20) / denom;
+        (T::ONE - v - w, v, w)
+    };
+
+    let pa = va.a * u + vb.a * v + vc.a * w;
+    let pb = va.b * u + vb.b * v + vc.b * w;
+    let mid = (pa + pb) * T::HALF;
+
+    // Normal points from the origin to the closest boundary point of `A - B`,
+    // which is the direction from `A` to `B`.
+    Contact {
+        point_a: pa.point(),
+        point_b: pb.point(),
+        plane: mid.plane(face.normal),
+        distance: -face.distance,
+    }
+}
+
+/// Runs EPA algorithm starting from the GJK simplex enclosing the origin.
+pub(super) fn run<T, A, B>(gjk: &Gjk<T>, a: &A, b: &B, mut simplex: Simplex<T>) -> Contact<T>
+where
+    T: Num,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let eps = gjk.epsilon;
+
+    complete(a, b, &mut simplex, eps);
+
+    if simplex.len < 4 {
+        // Minkowski difference is flat, shapes are touching.
+        let vertex = simplex.vertices[0];
+        let normal = if simplex.len == 3 {
+            let p = vertex.w;
+            let n = (simplex.vertices[1].w - p).cross(simplex.vertices[2].w - p);
+            n * n.norm2().sqrt().recip()
+        } else {
+            V3::new(T::ONE, T::ZERO, T::ZERO)
+        };
+
+        return Contact {
+            point_a: vertex.a.point(),
+            point_b: vertex.b.point(),
+            plane: vertex.a.plane(normal),
+            distance: T::ZERO,
+        };
+    }
+
+    let empty = Face {
+        indices: [0; 3],
+        normal: V3::ZERO,
+        distance: T::ZERO,
+        valid: false,
+    };
+
+    let mut polytope = Polytope {
+        vertices: [simplex.vertices[0]; MAX_VERTICES],
+        vertex_count: 4,
+        faces: [empty; MAX_FACES],
+        face_count: 0,
+    };
+    polytope.vertices[..4].copy_from_slice(&simplex.vertices);
+
+    // Orient tetrahedron faces outward.
+    let [p, q, r, s] = simplex.vertices.map(|v| v.w);
+    let flipped = (q - p).cross(r - p).dot(s - p) > T::ZERO;
+    let faces = if flipped {
+        [[0, 2, 1], [0, 1, 3], [0, 3, 2], [1, 2, 3]]
+    } else {
+        [[0, 1, 2], [0, 3, 1], [0, 2, 3], [1, 3, 2]]
+    };
+    for face in faces {
+        polytope.push_face(face);
+    }
+
+    let mut best = polytope.closest_face().unwrap_or(empty);
+
+    for _ in 0..gjk.max_iterations {
+        let Some(face) = polytope.closest_face() else {
+            break;
+        };
+        best = face;
+
+        let vertex = Vertex::support(a, b, face.normal);
+        let d = face.normal.dot(vertex.w);
+
+        if d - face.distance <= eps * d.abs() || !polytope.expand(vertex) {
+            break;
+        }
+    }
+
+    contact(&polytope, &best)
+}
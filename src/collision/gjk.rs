@@ -0,0 +1,278 @@
+use crate::Num;
+
+use super::{Contact, Gjk, SupportMap, Vertex, V3};
+
+/// Result of the GJK algorithm.
+pub(super) enum Outcome<T> {
+    /// Shapes are separated.
+    Separated(Contact<T>),
+
+    /// Shapes intersect.
+    /// Contains the final simplex that encloses the origin
+    /// or touches it when degenerate.
+    Intersecting(Simplex<T>),
+}
+
+/// Simplex of up to four vertices of the Minkowski difference.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct Simplex<T> {
+    pub vertices: [Vertex<T>; 4],
+    pub len: usize,
+}
+
+impl<T> Simplex<T>
+where
+    T: Num,
+{
+    fn new(vertex: Vertex<T>) -> Self {
+        Simplex {
+            vertices: [vertex; 4],
+            len: 1,
+        }
+    }
+
+    pub fn push(&mut self, vertex: Vertex<T>) {
+        self.vertices[self.len] = vertex;
+        self.len += 1;
+    }
+
+    fn contains(&self, w: V3<T>) -> bool {
+        self.vertices[..self.len].iter().any(|v| v.w == w)
+    }
+
+    /// Keeps only vertices with positive weights.
+    fn reduce(&mut self, weights: &mut [T; 4]) {
+        let mut len = 0;
+        for i in 0..self.len {
+            if weights[i] > T::ZERO {
+                self.vertices[len] = self.vertices[i];
+                weights[len] = weights[i];
+                len += 1;
+            }
+        }
+        self.len = len;
+    }
+
+    /// Finds the point of the simplex closest to the origin
+    /// and reduces the simplex to the smallest sub-simplex containing it.
+    ///
+    /// Returns `None` if the origin is inside the tetrahedron.
+    fn closest(&mut self, weights: &mut [T; 4]) -> Option<V3<T>> {
+        let [a, b, c, d] = self.vertices.map(|v| v.w);
+
+        *weights = match self.len {
+            1 => [T::ONE, T::ZERO, T::ZERO, T::ZERO],
+            2 => {
+                let [u, v] = segment(a, b);
+                [u, v, T::ZERO, T::ZERO]
+            }
+            3 => {
+                let [u, v, w] = triangle(a, b, c);
+                [u, v, w, T::ZERO]
+            }
+            _ => tetrahedron(a, b, c, d)?,
+        };
+
+        self.reduce(weights);
+
+        let mut closest = V3::ZERO;
+        for (vertex, &weight) in self.vertices[..self.len].iter().zip(weights.iter()) {
+            closest = closest + vertex.w * weight;
+        }
+        Some(closest)
+    }
+
+    /// Returns witness points on both shapes for the given weights.
+    fn witness(&self, weights: &[T; 4]) -> (V3<T>, V3<T>) {
+        let mut a = V3::ZERO;
+        let mut b = V3::ZERO;
+        for (vertex, &weight) in self.vertices[..self.len].iter().zip(weights) {
+            a = a + vertex.a * weight;
+            b = b + vertex.b * weight;
+        }
+        (a, b)
+    }
+}
+
+/// Barycentric coordinates of the point on the segment closest to the origin.
+fn segment<T>(a: V3<T>, b: V3<T>) -> [T; 2]
+where
+    T: Num,
+{
+    let ab = b - a;
+    let t = -a.dot(ab);
+    if t <= T::ZERO {
+        return [T::ONE, T::ZERO];
+    }
+
+    let denom = ab.norm2();
+    if t >= denom {
+        return [T::ZERO, T::ONE];
+    }
+
+    let t = t / denom;
+    [T::ONE - t, t]
+}
+
+/// Barycentric coordinates of the point on the triangle closest to the origin.
+fn triangle<T>(a: V3<T>, b: V3<T>, c: V3<T>) -> [T; 3]
+where
+    T: Num,
+{
+    let ab = b - a;
+    let ac = c - a;
+
+    let d1 = -ab.dot(a);
+    let d2 = -ac.dot(a);
+    if d1 <= T::ZERO && d2 <= T::ZERO {
+        return [T::ONE, T::ZERO, T::ZERO];
+    }
+
+    let d3 = -ab.dot(b);
+    let d4 = -ac.dot(b);
+    if d3 >= T::ZERO && d4 <= d3 {
+        return [T::ZERO, T::ONE, T::ZERO];
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= T::ZERO && d1 >= T::ZERO && d3 <= T::ZERO {
+        let v = d1 / (d1 - d3);
+        return [T::ONE - v, v, T::ZERO];
+    }
+
+    let d5 = -ab.dot(c);
+    let d6 = -ac.dot(c);
+    if d6 >= T::ZERO && d5 <= d6 {
+        return [T::ZERO, T::ZERO, T::ONE];
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= T::ZERO && d2 >= T::ZERO && d6 <= T::ZERO {
+        let w = d2 / (d2 - d6);
+        return [T::ONE - w, T::ZERO, w];
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= T::ZERO && d4 - d3 >= T::ZERO && d5 - d6 >= T::ZERO {
+        let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+        return [T::ZERO, T::ONE - w, w];
+    }
+
+    let denom = (va + vb + vc).recip();
+    let v = vb * denom;
+    let w = vc * denom;
+    [T::ONE - v - w, v, w]
+}
+
+/// Barycentric coordinates of the point on the tetrahedron closest to the origin.
+///
+/// Returns `None` if the origin is inside the tetrahedron.
+fn tetrahedron<T>(a: V3<T>, b: V3<T>, c: V3<T>, d: V3<T>) -> Option<[T; 4]>
+where
+    T: Num,
+{
+    // Each face with the index of the opposite vertex.
+    let faces = [
+        ([0, 1, 2], 3),
+        ([0, 2, 3], 1),
+        ([0, 3, 1], 2),
+        ([1, 3, 2], 0),
+    ];
+    let points = [a, b, c, d];
+
+    let mut best: Option<(T, [T; 4])> = None;
+
+    for (face, opposite) in faces {
+        let [p, q, r] = face.map(|i| points[i]);
+        let n = (q - p).cross(r - p);
+
+        let origin_side = -n.dot(p);
+        let opposite_side = n.dot(points[opposite] - p);
+
+        // Origin is on the same side as the opposite vertex.
+        if origin_side * opposite_side > T::ZERO {
+            continue;
+        }
+
+        let [u, v, w] = triangle(p, q, r);
+        let closest = p * u + q * v + r * w;
+        let dist = closest.norm2();
+
+        if best.is_none_or(|(best, _)| dist < best) {
+            let mut weights = [T::ZERO; 4];
+            weights[face[0]] = u;
+            weights[face[1]] = v;
+            weights[face[2]] = w;
+            best = Some((dist, weights));
+        }
+    }
+
+    best.map(|(_, weights)| weights)
+}
+
+/// Runs GJK algorithm on two shapes.
+pub(super) fn run<T, A, B>(gjk: &Gjk<T>, a: &A, b: &B) -> Outcome<T>
+where
+    T: Num,
+    A: SupportMap<T> + ?Sized,
+    B: SupportMap<T> + ?Sized,
+{
+    let eps = gjk.epsilon;
+
+    let first = Vertex::support(a, b, V3::new(T::ONE, T::ZERO, T::ZERO));
+    let mut simplex = Simplex::new(first);
+    let mut weights = [T::ONE, T::ZERO, T::ZERO, T::ZERO];
+    let mut v = first.w;
+    let mut scale = v.norm2();
+
+    for _ in 0..gjk.max_iterations {
+        let vv = v.norm2();
+        if vv <= eps * eps * scale {
+            return Outcome::Intersecting(simplex);
+        }
+
+        let vertex = Vertex::support(a, b, -v);
+        let w2 = vertex.w.norm2();
+        if w2 > scale {
+            scale = w2;
+        }
+
+        // No significant progress toward the origin.
+        if vv - v.dot(vertex.w) <= eps * vv || simplex.contains(vertex.w) {
+            break;
+        }
+
+        let previous = (simplex, weights);
+        simplex.push(vertex);
+
+        match simplex.closest(&mut weights) {
+            None => return Outcome::Intersecting(simplex),
+            Some(closest) => {
+                if closest.norm2() >= vv {
+                    // Numerical issues, keep the previous simplex.
+                    (simplex, weights) = previous;
+                    break;
+                }
+                v = closest;
+            }
+        }
+    }
+
+    let (pa, pb) = simplex.witness(&weights);
+    let diff = pb - pa;
+    let distance = diff.norm2().sqrt();
+
+    if distance == T::ZERO {
+        return Outcome::Intersecting(simplex);
+    }
+
+    let normal = diff * distance.recip();
+    let mid = (pa + pb) * T::HALF;
+
+    Outcome::Separated(Contact {
+        point_a: pa.point(),
+        point_b: pb.point(),
+        plane: mid.plane(normal),
+        distance,
+    })
+}
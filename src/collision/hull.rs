@@ -0,0 +1,451 @@
+use std::{cmp::Ordering, collections::HashSet, vec::Vec};
+
+use crate::{Line2, Num, Plane3, Point2, Point3};
+
+use super::V3;
+
+/// Edge of a convex hull in 2D.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HullEdge2<T> {
+    /// Line containing the edge.
+    ///
+    /// The line is normalized and points inside the hull are on its positive side.
+    pub line: Line2<T>,
+
+    /// Indices of the edge end points in the input slice.
+    pub indices: [usize; 2],
+}
+
+/// Face of a convex hull in 3D.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HullFace3<T> {
+    /// Plane containing the face.
+    ///
+    /// The plane is normalized and points inside the hull are on its positive side.
+    pub plane: Plane3<T>,
+
+    /// Indices of the face vertices in the input slice.
+    ///
+    /// Vertices are in counter-clockwise order when viewed from outside the hull.
+    pub indices: [usize; 3],
+}
+
+/// Builds convex hull of 2D points with monotone chain algorithm.
+///
+/// Edges are returned in counter-clockwise order.
+/// Ideal points are ignored.
+/// Returns an empty list if the points do not span an area.
+pub fn convex_hull2<T>(points: &[Point2<T>]) -> Vec<HullEdge2<T>>
+where
+    T: Num,
+{
+    let coords: Vec<(T, T)> = points
+        .iter()
+        .map(|point| point.normalized().coords())
+        .collect();
+
+    let mut order: Vec<usize> = (0..points.len())
+        .filter(|&i| !points[i].is_ideal())
+        .collect();
+
+    order.sort_by(|&a, &b| {
+        let (ax, ay) = coords[a];
+        let (bx, by) = coords[b];
+        compare(ax, bx).then_with(|| compare(ay, by))
+    });
+
+    let mut scale = T::ZERO;
+    for &i in &order {
+        let (x, y) = coords[i];
+        scale = larger(scale, larger(x.abs(), y.abs()));
+    }
+    let eps = T::EPSILON * scale * scale;
+
+    let cross = |o: usize, a: usize, b: usize| {
+        let (ox, oy) = coords[o];
+        let (ax, ay) = coords[a];
+        let (bx, by) = coords[b];
+        (ax - ox) * (by - oy) - (ay - oy) * (bx - ox)
+    };
+
+    let mut hull: Vec<usize> = Vec::with_capacity(order.len() + 1);
+
+    // Lower hull.
+    for &i in &order {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= eps {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+
+    // Upper hull.
+    let lower = hull.len() + 1;
+    for &i in order.iter().rev().skip(1) {
+        while hull.len() >= lower && cross(hull[hull.len() - 2], hull[hull.len() - 1], i) <= eps {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+
+    // Last point repeats the first one.
+    hull.pop();
+
+    if hull.len() < 3 {
+        return Vec::new();
+    }
+
+    let edge = |a: usize, b: usize| {
+        let (ax, ay) = coords[a];
+        let (bx, by) = coords[b];
+
+        // Inward normal is on the left of the edge.
+        let nx = ay - by;
+        let ny = bx - ax;
+
+        HullEdge2 {
+            line: Line2::from_abc(nx, ny, -(nx * ax + ny * ay)).normalized(),
+            indices: [a, b],
+        }
+    };
+
+    hull.iter()
+        .zip(hull.iter().cycle().skip(1))
+        .map(|(&a, &b)| edge(a, b))
+        .collect()
+}
+
+/// Builds convex hull of 3D points with quickhull algorithm.
+///
+/// Ideal points are ignored.
+/// Returns an empty list if the points do not span a volume.
+pub fn convex_hull3<T>(points: &[Point3<T>]) -> Vec<HullFace3<T>>
+where
+    T: Num,
+{
+    let coords: Vec<V3<T>> = points
+        .iter()
+        .map(|&point| {
+            if point.is_ideal() {
+                V3::ZERO
+            } else {
+                V3::from_point(point)
+            }
+        })
+        .collect();
+
+    let valid: Vec<usize> = (0..points.len())
+        .filter(|&i| !points[i].is_ideal())
+        .collect();
+
+    let mut max = V3::ZERO;
+    for &i in &valid {
+        let p = coords[i];
+        max = V3::new(
+            larger(max.x, p.x.abs()),
+            larger(max.y, p.y.abs()),
+            larger(max.z, p.z.abs()),
+        );
+    }
+    let eps = T::EPSILON * (max.x + max.y + max.z) * T::TWO;
+
+    let Some(simplex) = initial_simplex(&coords, &valid, eps) else {
+        return Vec::new();
+    };
+
+    let mut faces: Vec<Face<T>> = Vec::new();
+    for skip in 0..4 {
+        let mut indices = [0; 3];
+        let mut len = 0;
+        for (i, &index) in simplex.iter().enumerate() {
+            if i != skip {
+                indices[len] = index;
+                len += 1;
+            }
+        }
+
+        let mut face = Face::new(&coords, indices);
+        if face.distance(coords[simplex[skip]]) > T::ZERO {
+            face = Face::new(&coords, [indices[0], indices[2], indices[1]]);
+        }
+        faces.push(face);
+    }
+
+    for &i in &valid {
+        if !simplex.contains(&i) {
+            assign(&mut faces, &coords, i, eps);
+        }
+    }
+
+    while let Some(current) = faces
+        .iter()
+        .position(|face| face.alive && !face.outside.is_empty())
+    {
+        let face = &faces[current];
+        let mut apex = face.outside[0];
+        let mut apex_distance = face.distance(coords[apex]);
+        for &i in &face.outside[1..] {
+            let distance = face.distance(coords[i]);
+            if distance > apex_distance {
+                apex = i;
+                apex_distance = distance;
+            }
+        }
+
+        let p = coords[apex];
+        let visible: Vec<usize> = (0..faces.len())
+            .filter(|&i| faces[i].alive && faces[i].distance(p) > eps)
+            .collect();
+
+        // Horizon edges are edges of visible faces
+        // that are not shared with other visible faces.
+        let mut edges = Vec::with_capacity(visible.len() * 3);
+        for &i in &visible {
+            let [a, b, c] = faces[i].indices;
+            edges.extend([(a, b), (b, c), (c, a)]);
+        }
+        let set: HashSet<(usize, usize)> = edges.iter().copied().collect();
+
+        let mut orphans = Vec::new();
+        for &i in &visible {
+            faces[i].alive = false;
+            orphans.append(&mut faces[i].outside);
+        }
+
+        let first = faces.len();
+        for &(a, b) in &edges {
+            if !set.contains(&(b, a)) {
+                faces.push(Face::new(&coords, [a, b, apex]));
+            }
+        }
+
+        for i in orphans {
+            if i != apex {
+                assign(&mut faces[first..], &coords, i, eps);
+            }
+        }
+    }
+
+    faces
+        .iter()
+        .filter(|face| face.alive)
+        .map(|face| HullFace3 {
+            plane: coords[face.indices[0]].plane(-face.normal),
+            indices: face.indices,
+        })
+        .collect()
+}
+
+/// Face of the hull under construction.
+struct Face<T> {
+    indices: [usize; 3],
+
+    /// Outward normal.
+    normal: V3<T>,
+    offset: T,
+
+    /// Points outside of this face.
+    outside: Vec<usize>,
+    alive: bool,
+}
+
+impl<T> Face<T>
+where
+    T: Num,
+{
+    fn new(coords: &[V3<T>], indices: [usize; 3]) -> Self {
+        let [a, b, c] = indices.map(|i| coords[i]);
+        let n = (b - a).cross(c - a);
+        let norm2 = n.norm2();
+
+        let normal = if norm2 == T::ZERO {
+            V3::ZERO
+        } else {
+            n * norm2.sqrt().recip()
+        };
+
+        Face {
+            indices,
+            normal,
+            offset: normal.dot(a),
+            outside: Vec::new(),
+            alive: true,
+        }
+    }
+
+    /// Signed distance to the point, positive outside.
+    fn distance(&self, point: V3<T>) -> T {
+        self.normal.dot(point) - self.offset
+    }
+}
+
+/// Adds the point to outside set of the first face that sees it.
+fn assign<T>(faces: &mut [Face<T>], coords: &[V3<T>], index: usize, eps: T)
+where
+    T: Num,
+{
+    let p = coords[index];
+    if let Some(face) = faces
+        .iter_mut()
+        .find(|face| face.alive && face.distance(p) > eps)
+    {
+        face.outside.push(index);
+    }
+}
+
+/// Finds four points that span a tetrahedron.
+fn initial_simplex<T>(coords: &[V3<T>], valid: &[usize], eps: T) -> Option<[usize; 4]>
+where
+    T: Num,
+{
+    let (&first, rest) = valid.split_first()?;
+
+    // Two points furthest apart among extreme points on each axis.
+    let mut extremes = [first; 6];
+    for &i in rest {
+        let p = coords[i];
+        let axes = [p.x, p.y, p.z];
+        for (axis, &value) in axes.iter().enumerate() {
+            let min = coords[extremes[axis * 2]];
+            let max = coords[extremes[axis * 2 + 1]];
+            if value < [min.x, min.y, min.z][axis] {
+                extremes[axis * 2] = i;
+            }
+            if value > [max.x, max.y, max.z][axis] {
+                extremes[axis * 2 + 1] = i;
+            }
+        }
+    }
+
+    let mut a = first;
+    let mut b = first;
+    let mut best = T::ZERO;
+    for &i in &extremes {
+        for &j in &extremes {
+            let d = (coords[j] - coords[i]).norm2();
+            if d > best {
+                a = i;
+                b = j;
+                best = d;
+            }
+        }
+    }
+    if best <= eps * eps {
+        return None;
+    }
+
+    let pa = coords[a];
+    let ab = coords[b] - pa;
+
+    let c = furthest(valid, |i| ab.cross(coords[i] - pa).norm2())?;
+    let n = ab.cross(coords[c] - pa);
+    if n.norm2() <= eps * eps * ab.norm2() {
+        return None;
+    }
+
+    let d = furthest(valid, |i| n.dot(coords[i] - pa).abs())?;
+    let h = n.dot(coords[d] - pa);
+    if h * h <= eps * eps * n.norm2() {
+        return None;
+    }
+
+    Some([a, b, c, d])
+}
+
+/// Returns index with the largest value.
+fn furthest<T>(valid: &[usize], f: impl Fn(usize) -> T) -> Option<usize>
+where
+    T: Num,
+{
+    let mut best: Option<(usize, T)> = None;
+    for &i in valid {
+        let value = f(i);
+        if best.is_none_or(|(_, best)| value > best) {
+            best = Some((i, value));
+        }
+    }
+    best.map(|(i, _)| i)
+}
+
+fn compare<T>(a: T, b: T) -> Ordering
+where
+    T: Num,
+{
+    a.partial_cmp(&b).unwrap_or(Ordering::Equal)
+}
+
+fn larger<T>(a: T, b: T) -> T
+where
+    T: Num,
+{
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn square() {
+        let points = [
+            Point2::at(0.0, 0.0),
+            Point2::at(1.0, 0.0),
+            Point2::at(0.5, 0.5),
+            Point2::at(1.0, 1.0),
+            Point2::at(0.0, 1.0),
+        ];
+
+        let hull = convex_hull2(&points);
+        assert_eq!(hull.len(), 4);
+        assert!(hull.iter().all(|edge| !edge.indices.contains(&2)));
+        for (edge, next) in hull.iter().zip(hull.iter().cycle().skip(1)) {
+            assert_eq!(edge.indices[1], next.indices[0]);
+        }
+    }
+
+    #[test]
+    fn collinear_points_have_no_hull() {
+        let points = [
+            Point2::at(0.0, 0.0),
+            Point2::at(1.0, 1.0),
+            Point2::at(2.0, 2.0),
+        ];
+        assert!(convex_hull2(&points).is_empty());
+    }
+
+    #[test]
+    fn cube() {
+        let mut points = Vec::new();
+        for i in 0..8 {
+            let bit = |b: usize| if i & (1 << b) != 0 { 1.0 } else { 0.0 };
+            points.push(Point3::at(bit(0), bit(1), bit(2)));
+        }
+        points.push(Point3::at(0.5, 0.5, 0.5));
+
+        let hull = convex_hull3(&points);
+        assert_eq!(hull.len(), 12);
+        for face in &hull {
+            assert!(!face.indices.contains(&8));
+            for &point in &points {
+                assert!(face.plane.signed_distance(point) > -1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn coplanar_points_have_no_hull() {
+        let points = [
+            Point3::at(0.0, 0.0, 1.0),
+            Point3::at(1.0, 0.0, 1.0),
+            Point3::at(1.0, 1.0, 1.0),
+            Point3::at(0.0, 1.0, 1.0),
+            Point3::at(0.5, 0.2, 1.0),
+        ];
+        assert!(convex_hull3(&points).is_empty());
+    }
+}
@@ -0,0 +1,337 @@
+//! Contains convex collision detection.
+//!
+//! Shapes are described by support mappings
+//! and queried with GJK and EPA algorithms.
+//! Convex hulls of point sets are built with monotone chain and quickhull algorithms.
+
+mod epa;
+mod gjk;
+mod shapes;
+
+#[cfg(feature = "std")]
+mod hull;
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Num, Plane3, Point3};
+
+pub use self::shapes::{Ball3, Cuboid3, Posed, Segment3};
+
+#[cfg(feature = "std")]
+pub use self::hull::{convex_hull2, convex_hull3, HullEdge2, HullFace3};
+
+/// Support mapping of a convex shape.
+///
+/// This is the only thing GJK and EPA need to know about a shape.
+pub trait SupportMap<T> {
+    /// Returns the point of the shape that is furthest in the given direction.
+    ///
+    /// Direction is an ideal point.
+    fn support(&self, direction: Point3<T>) -> Point3<T>;
+}
+
+impl<T, S> SupportMap<T> for &S
+where
+    S: SupportMap<T> + ?Sized,
+{
+    #[inline]
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        S::support(self, direction)
+    }
+}
+
+/// Result of a proximity query between two convex shapes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Contact<T> {
+    /// Closest or deepest point on the first shape.
+    pub point_a: Point3<T>,
+
+    /// Closest or deepest point on the second shape.
+    pub point_b: Point3<T>,
+
+    /// Plane between the shapes.
+    ///
+    /// The plane is normalized and faces the second shape.
+    /// For penetrating shapes the normal is the direction
+    /// in which the second shape should move to resolve the penetration.
+    pub plane: Plane3<T>,
+
+    /// Distance between the shapes.
+    ///
+    /// Negative value is the penetration depth.
+    pub distance: T,
+}
+
+/// GJK and EPA solver for convex shapes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gjk<T> {
+    /// Maximum number of iterations for each algorithm.
+    pub max_iterations: usize,
+
+    /// Relative tolerance used to detect convergence.
+    pub epsilon: T,
+}
+
+impl<T> Default for Gjk<T>
+where
+    T: Num,
+{
+    fn default() -> Self {
+        Gjk::new()
+    }
+}
+
+impl<T> Gjk<T>
+where
+    T: Num,
+{
+    /// Creates a new solver with default parameters.
+    pub fn new() -> Self {
+        Gjk {
+            max_iterations: 64,
+            epsilon: T::EPSILON.sqrt(),
+        }
+    }
+
+    /// Returns true if the shapes intersect.
+    pub fn intersects<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: SupportMap<T> + ?Sized,
+        B: SupportMap<T> + ?Sized,
+    {
+        matches!(gjk::run(self, a, b), gjk::Outcome::Intersecting(_))
+    }
+
+    /// Finds closest points of two separated shapes.
+    ///
+    /// Returns `None` if the shapes intersect.
+    pub fn distance<A, B>(&self, a: &A, b: &B) -> Option<Contact<T>>
+    where
+        A: SupportMap<T> + ?Sized,
+        B: SupportMap<T> + ?Sized,
+    {
+        match gjk::run(self, a, b) {
+            gjk::Outcome::Separated(contact) => Some(contact),
+            gjk::Outcome::Intersecting(_) => None,
+        }
+    }
+
+    /// Finds closest points of separated shapes
+    /// or deepest points of penetrating shapes.
+    pub fn contact<A, B>(&self, a: &A, b: &B) -> Contact<T>
+    where
+        A: SupportMap<T> + ?Sized,
+        B: SupportMap<T> + ?Sized,
+    {
+        match gjk::run(self, a, b) {
+            gjk::Outcome::Separated(contact) => contact,
+            gjk::Outcome::Intersecting(simplex) => epa::run(self, a, b, simplex),
+        }
+    }
+}
+
+/// Euclidean vector used by the collision algorithms.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct V3<T> {
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> V3<T>
+where
+    T: Num,
+{
+    const ZERO: Self = V3 {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+    };
+
+    #[inline]
+    fn new(x: T, y: T, z: T) -> Self {
+        V3 { x, y, z }
+    }
+
+    #[inline]
+    fn from_point(point: Point3<T>) -> Self {
+        let (x, y, z) = point.normalized().coords();
+        V3 { x, y, z }
+    }
+
+    #[inline]
+    fn from_direction(direction: Point3<T>) -> Self {
+        let (x, y, z) = direction.coords();
+        V3 { x, y, z }
+    }
+
+    #[inline]
+    fn point(self) -> Point3<T> {
+        Point3::at(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn direction(self) -> Point3<T> {
+        Point3::ideal(self.x, self.y, self.z)
+    }
+
+    #[inline]
+    fn dot(self, rhs: Self) -> T {
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+
+    #[inline]
+    fn cross(self, rhs: Self) -> Self {
+        V3 {
+            x: self.y * rhs.z - self.z * rhs.y,
+            y: self.z * rhs.x - self.x * rhs.z,
+            z: self.x * rhs.y - self.y * rhs.x,
+        }
+    }
+
+    #[inline]
+    fn norm2(self) -> T {
+        self.dot(self)
+    }
+
+    /// Returns plane through the point with the given normal.
+    #[inline]
+    fn plane(self, normal: Self) -> Plane3<T> {
+        Plane3::new(-normal.dot(self), normal.x, normal.y, normal.z)
+    }
+}
+
+impl<T> Add for V3<T>
+where
+    T: Num,
+{
+    type Output = V3<T>;
+
+    #[inline]
+    fn add(self, rhs: V3<T>) -> V3<T> {
+        V3::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T> Sub for V3<T>
+where
+    T: Num,
+{
+    type Output = V3<T>;
+
+    #[inline]
+    fn sub(self, rhs: V3<T>) -> V3<T> {
+        V3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> Mul<T> for V3<T>
+where
+    T: Num,
+{
+    type Output = V3<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> V3<T> {
+        V3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> Neg for V3<T>
+where
+    T: Num,
+{
+    type Output = V3<T>;
+
+    #[inline]
+    fn neg(self) -> V3<T> {
+        V3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// Point of the Minkowski difference with its source points.
+#[derive(Clone, Copy, Debug)]
+struct Vertex<T> {
+    w: V3<T>,
+    a: V3<T>,
+    b: V3<T>,
+}
+
+impl<T> Vertex<T>
+where
+    T: Num,
+{
+    /// Finds support point of the Minkowski difference `a - b`.
+    #[inline]
+    fn support<A, B>(a: &A, b: &B, direction: V3<T>) -> Self
+    where
+        A: SupportMap<T> + ?Sized,
+        B: SupportMap<T> + ?Sized,
+    {
+        let a = V3::from_point(a.support(direction.direction()));
+        let b = V3::from_point(b.support((-direction).direction()));
+        Vertex { w: a - b, a, b }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Motor3;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{a} != {b}");
+    }
+
+    #[test]
+    fn distance_between_separated_balls() {
+        let a = Ball3::new(Point3::at(0.0, 0.0, 0.0), 1.0);
+        let b = Ball3::new(Point3::at(5.0, 0.0, 0.0), 2.0);
+
+        let gjk = Gjk::new();
+        assert!(!gjk.intersects(&a, &b));
+
+        let contact = gjk.distance(&a, &b).unwrap();
+        assert_close(contact.distance, 2.0);
+
+        let (x, y, z) = contact.point_a.normalized().coords();
+        assert_close(x, 1.0);
+        assert_close(y, 0.0);
+        assert_close(z, 0.0);
+        let (x, _, _) = contact.point_b.normalized().coords();
+        assert_close(x, 3.0);
+        assert_close(contact.plane.normal().x, 1.0);
+    }
+
+    #[test]
+    fn penetration_of_overlapping_boxes() {
+        let a = Cuboid3::new(1.0, 1.0, 1.0);
+        let b = Posed::new(
+            Motor3::translation(1.5, 0.2, 0.1),
+            Cuboid3::new(1.0, 1.0, 1.0),
+        );
+
+        let gjk = Gjk::new();
+        assert!(gjk.intersects(&a, &b));
+        assert!(gjk.distance(&a, &b).is_none());
+
+        let contact = gjk.contact(&a, &b);
+        assert_close(contact.distance, -0.5);
+        let normal = contact.plane.normal();
+        assert_close(normal.x, 1.0);
+        assert_close(normal.y, 0.0);
+        assert_close(normal.z, 0.0);
+    }
+
+    #[test]
+    fn distance_to_rotated_box() {
+        let axis = Point3::at(0.0, 0.0, 0.0).join(Point3::at(0.0, 0.0, 1.0));
+        let motor = Motor3::translation(4.0, 0.0, 0.0)
+            * Motor3::rotation(axis, core::f64::consts::FRAC_PI_4);
+        let b = Posed::new(motor, Cuboid3::new(1.0, 1.0, 1.0));
+        let a = Point3::at(0.0, 0.0, 0.0);
+
+        let contact = Gjk::new().distance(&a, &b).unwrap();
+        assert_close(contact.distance, 4.0 - 2.0f64.sqrt());
+    }
+}
@@ -0,0 +1,211 @@
+use crate::{Motor3, Num, Point3};
+
+use super::{SupportMap, V3};
+
+impl<T> SupportMap<T> for Point3<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn support(&self, _direction: Point3<T>) -> Point3<T> {
+        *self
+    }
+}
+
+/// Convex hull of the points.
+///
+/// # Panics
+///
+/// Panics if the slice is empty.
+impl<T> SupportMap<T> for [Point3<T>]
+where
+    T: Num,
+{
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        let d = V3::from_direction(direction);
+
+        let mut points = self.iter();
+        let first = *points
+            .next()
+            .expect("Convex hull must have at least one point");
+
+        let mut best = first;
+        let mut best_dot = V3::from_point(first).dot(d);
+
+        for &point in points {
+            let dot = V3::from_point(point).dot(d);
+            if dot > best_dot {
+                best = point;
+                best_dot = dot;
+            }
+        }
+
+        best
+    }
+}
+
+/// Convex hull of the points.
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+impl<T, const N: usize> SupportMap<T> for [Point3<T>; N]
+where
+    T: Num,
+{
+    #[inline]
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        self[..].support(direction)
+    }
+}
+
+/// Line segment between two points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment3<T> {
+    a: Point3<T>,
+    b: Point3<T>,
+}
+
+impl<T> Segment3<T>
+where
+    T: Num,
+{
+    /// Creates a new segment between two points.
+    pub const fn new(a: Point3<T>, b: Point3<T>) -> Self {
+        Segment3 { a, b }
+    }
+
+    /// Returns end points of the segment.
+    pub const fn points(&self) -> (Point3<T>, Point3<T>) {
+        (self.a, self.b)
+    }
+}
+
+impl<T> SupportMap<T> for Segment3<T>
+where
+    T: Num,
+{
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        let d = V3::from_direction(direction);
+
+        if V3::from_point(self.a).dot(d) >= V3::from_point(self.b).dot(d) {
+            self.a
+        } else {
+            self.b
+        }
+    }
+}
+
+/// Solid ball.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ball3<T> {
+    center: Point3<T>,
+    radius: T,
+}
+
+impl<T> Ball3<T>
+where
+    T: Num,
+{
+    /// Creates a new ball with the given center and radius.
+    pub const fn new(center: Point3<T>, radius: T) -> Self {
+        Ball3 { center, radius }
+    }
+
+    /// Returns center of the ball.
+    pub const fn center(&self) -> Point3<T> {
+        self.center
+    }
+
+    /// Returns radius of the ball.
+    pub const fn radius(&self) -> T {
+        self.radius
+    }
+}
+
+impl<T> SupportMap<T> for Ball3<T>
+where
+    T: Num,
+{
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        let d = V3::from_direction(direction);
+        let norm2 = d.norm2();
+
+        if norm2 == T::ZERO {
+            return self.center.normalized();
+        }
+
+        let c = V3::from_point(self.center);
+        (c + d * (self.radius / norm2.sqrt())).point()
+    }
+}
+
+/// Box centered at the origin and aligned to axes.
+///
+/// Use [`Posed`] to place it elsewhere.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cuboid3<T> {
+    half_extents: (T, T, T),
+}
+
+impl<T> Cuboid3<T>
+where
+    T: Num,
+{
+    /// Creates a new box with the given half extents.
+    pub const fn new(hx: T, hy: T, hz: T) -> Self {
+        Cuboid3 {
+            half_extents: (hx, hy, hz),
+        }
+    }
+
+    /// Returns half extents of the box.
+    pub const fn half_extents(&self) -> (T, T, T) {
+        self.half_extents
+    }
+}
+
+impl<T> SupportMap<T> for Cuboid3<T>
+where
+    T: Num,
+{
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        let (x, y, z) = direction.coords();
+        let (hx, hy, hz) = self.half_extents;
+
+        let pick = |d: T, h: T| if d < T::ZERO { -h } else { h };
+        Point3::at(pick(x, hx), pick(y, hy), pick(z, hz))
+    }
+}
+
+/// Shape placed in the world by a motor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Posed<T, S> {
+    /// Motor that moves the shape from its local space.
+    pub motor: Motor3<T>,
+
+    /// The shape in its local space.
+    pub shape: S,
+}
+
+impl<T, S> Posed<T, S> {
+    /// Places the shape with the motor.
+    pub const fn new(motor: Motor3<T>, shape: S) -> Self {
+        Posed { motor, shape }
+    }
+}
+
+impl<T, S> SupportMap<T> for Posed<T, S>
+where
+    T: Num,
+    S: SupportMap<T>,
+{
+    fn support(&self, direction: Point3<T>) -> Point3<T> {
+        let local = self.motor.reverse().move_point(direction);
+        self.motor.move_point(self.shape.support(local))
+    }
+}
@@ -0,0 +1,236 @@
+//! Euler angles.
+//!
+//! Rotations can be created from three angles around coordinate axes
+//! applied in the order given by [`EulerRot`] and converted back.
+
+use crate::{Matrix3, Motor3, Num, Quaternion, Vector3};
+
+/// Order of rotations for Euler angles.
+///
+/// Intrinsic orders rotate around axes of the rotating frame,
+/// so `XYZ` rotates around X, then around the new Y, then around the new Z.
+/// Extrinsic orders with `Ex` suffix rotate around fixed world axes.
+///
+/// Intrinsic order is equivalent to the reversed extrinsic order
+/// with angles in reversed order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EulerRot {
+    /// Intrinsic rotation around X, Y and Z axes.
+    XYZ,
+    /// Intrinsic rotation around X, Z and Y axes.
+    XZY,
+    /// Intrinsic rotation around Y, X and Z axes.
+    YXZ,
+    /// Intrinsic rotation around Y, Z and X axes.
+    YZX,
+    /// Intrinsic rotation around Z, X and Y axes.
+    ZXY,
+    /// Intrinsic rotation around Z, Y and X axes.
+    ZYX,
+
+    /// Extrinsic rotation around X, Y and Z axes.
+    XYZEx,
+    /// Extrinsic rotation around X, Z and Y axes.
+    XZYEx,
+    /// Extrinsic rotation around Y, X and Z axes.
+    YXZEx,
+    /// Extrinsic rotation around Y, Z and X axes.
+    YZXEx,
+    /// Extrinsic rotation around Z, X and Y axes.
+    ZXYEx,
+    /// Extrinsic rotation around Z, Y and X axes.
+    ZYXEx,
+}
+
+impl EulerRot {
+    /// Returns axis indices of the equivalent intrinsic order
+    /// and whether angles have to be reversed.
+    fn intrinsic(self) -> ([usize; 3], bool) {
+        match self {
+            EulerRot::XYZ => ([0, 1, 2], false),
+            EulerRot::XZY => ([0, 2, 1], false),
+            EulerRot::YXZ => ([1, 0, 2], false),
+            EulerRot::YZX => ([1, 2, 0], false),
+            EulerRot::ZXY => ([2, 0, 1], false),
+            EulerRot::ZYX => ([2, 1, 0], false),
+            EulerRot::XYZEx => ([2, 1, 0], true),
+            EulerRot::XZYEx => ([1, 2, 0], true),
+            EulerRot::YXZEx => ([2, 0, 1], true),
+            EulerRot::YZXEx => ([0, 2, 1], true),
+            EulerRot::ZXYEx => ([1, 0, 2], true),
+            EulerRot::ZYXEx => ([0, 1, 2], true),
+        }
+    }
+}
+
+/// Returns unit vector along the axis.
+fn axis<T>(index: usize) -> Vector3<T>
+where
+    T: Num,
+{
+    let mut axis = Vector3::ZERO;
+    axis[index] = T::ONE;
+    axis
+}
+
+impl<T> Quaternion<T>
+where
+    T: Num,
+{
+    /// Creates a rotation from Euler angles in radians.
+    pub fn from_euler(order: EulerRot, a: T, b: T, c: T) -> Self {
+        let ([i, j, k], reversed) = order.intrinsic();
+        let (a, c) = if reversed { (c, a) } else { (a, c) };
+
+        Quaternion::from_axis_angle(axis(i), a)
+            * Quaternion::from_axis_angle(axis(j), b)
+            * Quaternion::from_axis_angle(axis(k), c)
+    }
+
+    /// Returns Euler angles in radians of this rotation.
+    ///
+    /// The quaternion must be normalized.
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        self.to_matrix().to_euler(order)
+    }
+}
+
+impl<T> Matrix3<T>
+where
+    T: Num,
+{
+    /// Creates a rotation matrix from Euler angles in radians.
+    pub fn from_euler(order: EulerRot, a: T, b: T, c: T) -> Self {
+        Quaternion::from_euler(order, a, b, c).to_matrix()
+    }
+
+    /// Returns Euler angles in radians of this rotation matrix.
+    ///
+    /// The matrix must be orthonormal with determinant 1.
+    /// In gimbal lock the third rotation of the intrinsic order is set to zero.
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        let ([i, j, k], reversed) = order.intrinsic();
+
+        let e = self.arrays();
+        let m = |row: usize, col: usize| e[col][row];
+
+        // Sign of the axes permutation.
+        let s = if (j + 3 - i) % 3 == 1 {
+            T::ONE
+        } else {
+            -T::ONE
+        };
+
+        let mut sin_b = s * m(i, k);
+        if sin_b > T::ONE {
+            sin_b = T::ONE;
+        }
+        if sin_b < -T::ONE {
+            sin_b = -T::ONE;
+        }
+        let b = sin_b.asin();
+
+        let (a, c) = if T::ONE - sin_b.abs() > T::EPSILON.sqrt() {
+            let a = (-s * m(j, k)).atan2(m(k, k));
+            let c = (-s * m(i, j)).atan2(m(i, i));
+            (a, c)
+        } else {
+            let a = (s * m(k, j)).atan2(m(j, j));
+            (a, T::ZERO)
+        };
+
+        if reversed {
+            (c, b, a)
+        } else {
+            (a, b, c)
+        }
+    }
+}
+
+impl<T> Motor3<T>
+where
+    T: Num,
+{
+    /// Creates a rotation around the origin from Euler angles in radians.
+    pub fn from_euler(order: EulerRot, a: T, b: T, c: T) -> Self {
+        Quaternion::from_euler(order, a, b, c).into()
+    }
+
+    /// Returns Euler angles in radians of the rotation part of this motor.
+    ///
+    /// Translation is discarded.
+    pub fn to_euler(&self, order: EulerRot) -> (T, T, T) {
+        Quaternion::from(*self).normalized().to_euler(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORDERS: [EulerRot; 12] = [
+        EulerRot::XYZ,
+        EulerRot::XZY,
+        EulerRot::YXZ,
+        EulerRot::YZX,
+        EulerRot::ZXY,
+        EulerRot::ZYX,
+        EulerRot::XYZEx,
+        EulerRot::XZYEx,
+        EulerRot::YXZEx,
+        EulerRot::YZXEx,
+        EulerRot::ZXYEx,
+        EulerRot::ZYXEx,
+    ];
+
+    fn assert_close(a: (f64, f64, f64), b: (f64, f64, f64)) {
+        let d = (a.0 - b.0).abs() + (a.1 - b.1).abs() + (a.2 - b.2).abs();
+        assert!(d < 1e-12, "{a:?} != {b:?}");
+    }
+
+    fn assert_same_rotation(a: Quaternion<f64>, b: Quaternion<f64>) {
+        assert!((a.dot(&b).abs() - 1.0).abs() < 1e-12, "{a:?} != {b:?}");
+    }
+
+    #[test]
+    fn round_trip() {
+        let angles = (0.3, -0.4, 1.1);
+        for order in ORDERS {
+            let q = Quaternion::from_euler(order, angles.0, angles.1, angles.2);
+            assert_close(q.to_euler(order), angles);
+
+            let m = Matrix3::from_euler(order, angles.0, angles.1, angles.2);
+            assert_close(m.to_euler(order), angles);
+
+            let motor = Motor3::from_euler(order, angles.0, angles.1, angles.2);
+            assert_close(motor.to_euler(order), angles);
+        }
+    }
+
+    #[test]
+    fn extrinsic_is_reversed_intrinsic() {
+        let q = Quaternion::from_euler(EulerRot::XYZEx, 0.3, -0.4, 1.1);
+        let r = Quaternion::from_euler(EulerRot::ZYX, 1.1, -0.4, 0.3);
+        assert_same_rotation(q, r);
+    }
+
+    #[test]
+    fn intrinsic_rotates_around_moving_axes() {
+        let q = Quaternion::from_euler(EulerRot::XYZ, 0.3, -0.4, 1.1);
+        let r = Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.3)
+            * Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), -0.4)
+            * Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), 1.1);
+        assert_same_rotation(q, r);
+    }
+
+    #[test]
+    fn gimbal_lock() {
+        let b = core::f64::consts::FRAC_PI_2;
+        for order in ORDERS {
+            let q = Quaternion::from_euler(order, 0.3, b, 0.5);
+            let (a, b, c) = q.to_euler(order);
+            assert_same_rotation(Quaternion::from_euler(order, a, b, c), q);
+        }
+    }
+}
@@ -13,11 +13,28 @@ macro_rules! count {
 mod scalar;
 // mod simd;
 
+pub mod algebra;
+
+mod cga;
+mod collision;
+mod euler;
 mod matrix;
+mod npga;
 mod pga;
+mod quaternion;
 mod vector;
 
-pub use self::{matrix::*, pga::*, scalar::*, vector::*};
+pub use self::{
+    cga::*,
+    collision::*,
+    euler::*,
+    matrix::*,
+    npga::{elliptic, hyperbolic},
+    pga::*,
+    quaternion::*,
+    scalar::*,
+    vector::*,
+};
 
 #[cfg(feature = "serde")]
 mod array_init;
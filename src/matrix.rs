@@ -3,11 +3,32 @@
 
 use core::{
     mem::{ManuallyDrop, MaybeUninit},
-    ops::{Mul, MulAssign},
+    ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
 use crate::{Num, Vector};
 
+pub use self::{cholesky::Cholesky, eigen::SymmetricEigen, lu::Lu, qr::Qr, svd::Svd};
+
+mod cholesky;
+mod eigen;
+mod lu;
+mod qr;
+mod solve;
+mod svd;
+
+/// Returns precision of decompositions of matrix with the given size and largest magnitude.
+fn tolerance<T>(size: usize, scale: T) -> T
+where
+    T: Num,
+{
+    let mut tolerance = T::ZERO;
+    for _ in 0..size {
+        tolerance += T::EPSILON * scale;
+    }
+    tolerance
+}
+
 /// Column-major matrix type.
 /// `N` is the number of columns = row size.
 /// `M` is the number of rows = column size.
@@ -143,6 +164,82 @@ impl<T, const N: usize> Matrix<T, N, N> {
     }
 }
 
+impl<T> Matrix<T, 4>
+where
+    T: Num,
+{
+    /// Transforms all points in place by this homogeneous matrix.
+    ///
+    /// Points have implicit `w = 1` and the result is divided by its `w`.
+    pub fn transform_points(&self, points: &mut [Vector<T, 3>]) {
+        for point in points {
+            *point = self.transform_point(*point);
+        }
+    }
+
+    /// Transforms points from `src` by this homogeneous matrix and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn transform_points_into(&self, src: &[Vector<T, 3>], dst: &mut [Vector<T, 3>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        for (point, out) in src.iter().zip(dst) {
+            *out = self.transform_point(*point);
+        }
+    }
+
+    /// Transforms all directions in place by this homogeneous matrix.
+    ///
+    /// Directions have implicit `w = 0`, so translation is ignored.
+    pub fn transform_vectors(&self, vectors: &mut [Vector<T, 3>]) {
+        for vector in vectors {
+            *vector = self.transform_vector(*vector);
+        }
+    }
+
+    /// Transforms directions from `src` by this homogeneous matrix and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn transform_vectors_into(&self, src: &[Vector<T, 3>], dst: &mut [Vector<T, 3>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        for (vector, out) in src.iter().zip(dst) {
+            *out = self.transform_vector(*vector);
+        }
+    }
+
+    #[inline(always)]
+    fn transform_point(&self, point: Vector<T, 3>) -> Vector<T, 3> {
+        let [x, y, z] = point.into_array();
+        let [c0, c1, c2, c3] = &self.e;
+
+        let w = c0[3] * x + c1[3] * y + c2[3] * z + c3[3];
+        let w = if w == T::ZERO { T::ONE } else { w.recip() };
+
+        Vector::from_array([
+            (c0[0] * x + c1[0] * y + c2[0] * z + c3[0]) * w,
+            (c0[1] * x + c1[1] * y + c2[1] * z + c3[1]) * w,
+            (c0[2] * x + c1[2] * y + c2[2] * z + c3[2]) * w,
+        ])
+    }
+
+    #[inline(always)]
+    fn transform_vector(&self, vector: Vector<T, 3>) -> Vector<T, 3> {
+        let [x, y, z] = vector.into_array();
+        let [c0, c1, c2, _] = &self.e;
+
+        Vector::from_array([
+            c0[0] * x + c1[0] * y + c2[0] * z,
+            c0[1] * x + c1[1] * y + c2[1] * z,
+            c0[2] * x + c1[2] * y + c2[2] * z,
+        ])
+    }
+}
+
 impl<T, const M: usize> Matrix<T, 1, M> {
     /// Create a new column vector.
     #[inline(always)]
@@ -176,14 +273,14 @@ impl<T, const N: usize> Matrix<T, N, 1> {
     }
 }
 
-impl<T, const N: usize, const M: usize, const K: usize> Mul<&Matrix<T, K, M>> for &Matrix<T, N, K>
+impl<T, const N: usize, const M: usize, const K: usize> Mul<&Matrix<T, N, K>> for &Matrix<T, K, M>
 where
     T: Num,
 {
     type Output = Matrix<T, N, M>;
 
     #[inline]
-    fn mul(self, rhs: &Matrix<T, K, M>) -> Self::Output {
+    fn mul(self, rhs: &Matrix<T, N, K>) -> Self::Output {
         let mut result = Matrix::<T, N, M> {
             e: [[T::ZERO; M]; N],
         };
@@ -191,7 +288,7 @@ where
         for n in 0..N {
             for m in 0..M {
                 for k in 0..K {
-                    result.e[n][m] += self.e[n][k] * rhs.e[k][m];
+                    result.e[n][m] += self.e[k][m] * rhs.e[n][k];
                 }
             }
         }
@@ -200,38 +297,38 @@ where
     }
 }
 
-impl<T, const N: usize, const M: usize, const K: usize> Mul<Matrix<T, K, M>> for &Matrix<T, N, K>
+impl<T, const N: usize, const M: usize, const K: usize> Mul<Matrix<T, N, K>> for &Matrix<T, K, M>
 where
     T: Num,
 {
     type Output = Matrix<T, N, M>;
 
     #[inline(always)]
-    fn mul(self, rhs: Matrix<T, K, M>) -> Self::Output {
+    fn mul(self, rhs: Matrix<T, N, K>) -> Self::Output {
         self.mul(&rhs)
     }
 }
 
-impl<T, const N: usize, const M: usize, const K: usize> Mul<&Matrix<T, K, M>> for Matrix<T, N, K>
+impl<T, const N: usize, const M: usize, const K: usize> Mul<&Matrix<T, N, K>> for Matrix<T, K, M>
 where
     T: Num,
 {
     type Output = Matrix<T, N, M>;
 
     #[inline(always)]
-    fn mul(self, rhs: &Matrix<T, K, M>) -> Self::Output {
+    fn mul(self, rhs: &Matrix<T, N, K>) -> Self::Output {
         (&self).mul(rhs)
     }
 }
 
-impl<T, const N: usize, const M: usize, const K: usize> Mul<Matrix<T, K, M>> for Matrix<T, N, K>
+impl<T, const N: usize, const M: usize, const K: usize> Mul<Matrix<T, N, K>> for Matrix<T, K, M>
 where
     T: Num,
 {
     type Output = Matrix<T, N, M>;
 
     #[inline(always)]
-    fn mul(self, rhs: Matrix<T, K, M>) -> Self::Output {
+    fn mul(self, rhs: Matrix<T, N, K>) -> Self::Output {
         (&self).mul(&rhs)
     }
 }
@@ -256,6 +353,60 @@ where
     }
 }
 
+impl<T, const N: usize, const M: usize> Add<Matrix<T, N, M>> for Matrix<T, N, M>
+where
+    T: Num,
+{
+    type Output = Matrix<T, N, M>;
+
+    #[inline(always)]
+    fn add(mut self, rhs: Matrix<T, N, M>) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<T, const N: usize, const M: usize> AddAssign<Matrix<T, N, M>> for Matrix<T, N, M>
+where
+    T: Num,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: Matrix<T, N, M>) {
+        for (column, rhs) in self.e.iter_mut().zip(&rhs.e) {
+            for (e, &rhs) in column.iter_mut().zip(rhs) {
+                *e += rhs;
+            }
+        }
+    }
+}
+
+impl<T, const N: usize, const M: usize> Sub<Matrix<T, N, M>> for Matrix<T, N, M>
+where
+    T: Num,
+{
+    type Output = Matrix<T, N, M>;
+
+    #[inline(always)]
+    fn sub(mut self, rhs: Matrix<T, N, M>) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<T, const N: usize, const M: usize> SubAssign<Matrix<T, N, M>> for Matrix<T, N, M>
+where
+    T: Num,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Matrix<T, N, M>) {
+        for (column, rhs) in self.e.iter_mut().zip(&rhs.e) {
+            for (e, &rhs) in column.iter_mut().zip(rhs) {
+                *e -= rhs;
+            }
+        }
+    }
+}
+
 impl<T, const N: usize, const M: usize> Mul<&Vector<T, N>> for &Matrix<T, N, M>
 where
     T: Num,
@@ -427,3 +578,19 @@ pub type Matrix3<T> = Matrix<T, 3>;
 
 /// Matrix with 4 columns and 4 rows.
 pub type Matrix4<T> = Matrix<T, 4>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn product_order() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0], [3.0, 4.0]]);
+        let b = Matrix::from_row_arrays([[0.0, 1.0], [5.0, -1.0]]);
+        assert_eq!(a * b, Matrix::from_row_arrays([[10.0, -1.0], [20.0, -1.0]]));
+
+        // Agrees with applying both matrices to a vector in turn.
+        let v = Vector::from_array([2.0, -3.0]);
+        assert_eq!((a * b) * v, a * (b * v));
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{Num, Vector};
+
+use super::Matrix;
+
+/// Cholesky decomposition of symmetric positive definite matrix.
+///
+/// The matrix is equal to `L * L^T`, where `L` is lower triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Cholesky<T, const N: usize> {
+    l: Matrix<T, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Num,
+{
+    /// Computes Cholesky decomposition.
+    ///
+    /// Only the lower triangle of the matrix is used.
+    /// Returns `None` if the matrix is not positive definite.
+    pub fn cholesky(&self) -> Option<Cholesky<T, N>> {
+        let mut l = [[T::ZERO; N]; N];
+
+        for j in 0..N {
+            let mut d = self.e[j][j];
+            for column in &l[..j] {
+                d -= column[j] * column[j];
+            }
+
+            if d <= T::ZERO {
+                return None;
+            }
+
+            let d = d.sqrt();
+            l[j][j] = d;
+
+            for i in j + 1..N {
+                let mut e = self.e[j][i];
+                for column in &l[..j] {
+                    e -= column[i] * column[j];
+                }
+                l[j][i] = e / d;
+            }
+        }
+
+        Some(Cholesky {
+            l: Matrix::from_column_arrays(l),
+        })
+    }
+}
+
+impl<T, const N: usize> Cholesky<T, N>
+where
+    T: Num,
+{
+    /// Returns the lower triangular factor.
+    pub const fn l(&self) -> Matrix<T, N> {
+        self.l
+    }
+
+    /// Returns determinant of the matrix.
+    pub fn determinant(&self) -> T {
+        let mut det = T::ONE;
+        for i in 0..N {
+            det *= self.l.e[i][i];
+        }
+        det * det
+    }
+
+    /// Solves `A * x = b` for `x`.
+    pub fn solve(&self, b: &Vector<T, N>) -> Vector<T, N> {
+        let l = &self.l.e;
+        let mut x = *b;
+
+        // Forward substitution with `L`.
+        for j in 0..N {
+            x[j] /= l[j][j];
+            let xj = x[j];
+            for i in j + 1..N {
+                x[i] -= l[j][i] * xj;
+            }
+        }
+
+        // Back substitution with `L^T`.
+        for i in (0..N).rev() {
+            let mut xi = x[i];
+            for k in i + 1..N {
+                xi -= l[i][k] * x[k];
+            }
+            x[i] = xi / l[i][i];
+        }
+
+        x
+    }
+
+    /// Returns inverse of the matrix.
+    pub fn inverse(&self) -> Matrix<T, N> {
+        let mut inverse = Matrix::identity();
+        for column in &mut inverse.e {
+            *column = self.solve(&Vector::from_array(*column)).into_array();
+        }
+        inverse
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn factors_positive_definite_matrix() {
+        let a = Matrix::from_row_arrays([[4.0, 2.0, 0.0], [2.0, 5.0, 1.0], [0.0, 1.0, 2.0]]);
+        let cholesky = a.cholesky().unwrap();
+        let l = cholesky.l();
+
+        for (p, a) in (l * l.transpose()).arrays().iter().zip(a.arrays()) {
+            for (p, a) in p.iter().zip(a) {
+                assert!((p - a).abs() < 1e-12);
+            }
+        }
+        assert!((cholesky.determinant() - 28.0).abs() < 1e-12);
+
+        let x = cholesky.solve(&Vector::from_array([6.0, 8.0, 3.0]));
+        for (x, e) in x.into_array().iter().zip([1.0, 1.0, 1.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn rejects_indefinite_matrix() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0], [2.0, 1.0]]);
+        assert!(a.cholesky().is_none());
+    }
+}
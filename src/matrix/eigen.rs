@@ -0,0 +1,144 @@
+use crate::{Num, Vector};
+
+use super::Matrix;
+
+/// Eigen decomposition of symmetric matrix.
+///
+/// The matrix is equal to `V * D * V^T`,
+/// where `D` is diagonal matrix of eigenvalues and `V` is orthonormal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SymmetricEigen<T, const N: usize> {
+    /// Eigenvalues in descending order.
+    pub values: Vector<T, N>,
+
+    /// Matrix with the corresponding unit eigenvectors in columns.
+    pub vectors: Matrix<T, N>,
+}
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Num,
+{
+    /// Computes eigen decomposition of symmetric matrix with cyclic Jacobi method.
+    ///
+    /// The matrix is assumed to be symmetric.
+    pub fn symmetric_eigen(&self) -> SymmetricEigen<T, N> {
+        let mut a = self.e;
+        let mut v = Matrix::<T, N>::identity().e;
+
+        for _ in 0..64 {
+            let mut off = T::ZERO;
+            let mut diag = T::ZERO;
+            for (i, row) in a.iter().enumerate() {
+                for (j, &e) in row.iter().enumerate() {
+                    if i == j {
+                        diag += e * e;
+                    } else {
+                        off += e * e;
+                    }
+                }
+            }
+
+            if off <= T::EPSILON * T::EPSILON * diag {
+                break;
+            }
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    if a[p][q] == T::ZERO {
+                        continue;
+                    }
+
+                    let theta = (a[q][q] - a[p][p]) / (T::TWO * a[p][q]);
+                    let t = (theta * theta + T::ONE).sqrt() + theta.abs();
+                    let t = if theta < T::ZERO {
+                        -t.recip()
+                    } else {
+                        t.recip()
+                    };
+                    let c = (t * t + T::ONE).sqrt().recip();
+                    let s = t * c;
+
+                    for row in a.iter_mut() {
+                        let (ap, aq) = (row[p], row[q]);
+                        row[p] = c * ap - s * aq;
+                        row[q] = s * ap + c * aq;
+                    }
+                    let (head, tail) = a.split_at_mut(q);
+                    for (ap, aq) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        (*ap, *aq) = (c * *ap - s * *aq, s * *ap + c * *aq);
+                    }
+                    for row in v.iter_mut() {
+                        let (vp, vq) = (row[p], row[q]);
+                        row[p] = c * vp - s * vq;
+                        row[q] = s * vp + c * vq;
+                    }
+                }
+            }
+        }
+
+        // `v` is stored by rows with eigenvectors in its columns.
+        let v = Matrix::from_row_arrays(v);
+        let mut values = [T::ZERO; N];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = a[i][i];
+        }
+
+        let order = descending(&values);
+        SymmetricEigen {
+            values: Vector::from_array(order.map(|i| values[i])),
+            vectors: Matrix::from_column_arrays(order.map(|i| v.e[i])),
+        }
+    }
+}
+
+/// Returns indices that sort values in descending order.
+pub(super) fn descending<T, const N: usize>(values: &[T; N]) -> [usize; N]
+where
+    T: Num,
+{
+    let mut order = [0; N];
+    for (i, o) in order.iter_mut().enumerate() {
+        *o = i;
+    }
+
+    // Insertion sort, `N` is small.
+    for i in 1..N {
+        let mut j = i;
+        while j > 0 && values[order[j - 1]] < values[order[j]] {
+            order.swap(j - 1, j);
+            j -= 1;
+        }
+    }
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn eigenvectors_are_orthonormal() {
+        let a = Matrix::from_row_arrays([[2.0, 1.0, 0.0], [1.0, 2.0, 0.0], [0.0, 0.0, 5.0]]);
+        let eigen = a.symmetric_eigen();
+
+        let values = eigen.values.into_array();
+        for (v, e) in values.iter().zip([5.0, 3.0, 1.0]) {
+            assert!((v - e).abs() < 1e-12, "{values:?}");
+        }
+
+        let vectors = eigen.vectors.arrays();
+        for (i, (vector, value)) in vectors.iter().zip(values).enumerate() {
+            let av = (a * Vector::from_array(*vector)).into_array();
+            for (av, v) in av.iter().zip(vector) {
+                assert!((av - value * v).abs() < 1e-12);
+            }
+
+            for (j, other) in vectors.iter().enumerate() {
+                let dot: f64 = vector.iter().zip(other).map(|(a, b)| a * b).sum();
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((dot - expected).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use crate::{Num, Vector};
+
+use super::{tolerance, Matrix};
+
+/// LU decomposition with partial pivoting.
+///
+/// Rows of the matrix permuted by [`permutation`](Self::permutation)
+/// are equal to `L * U`, where `L` is unit lower triangular
+/// and `U` is upper triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Lu<T, const N: usize> {
+    lu: Matrix<T, N>,
+    permutation: [usize; N],
+    odd: bool,
+    tolerance: T,
+}
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Num,
+{
+    /// Computes LU decomposition with partial pivoting.
+    pub fn lu(&self) -> Lu<T, N> {
+        let mut a = self.e;
+        let mut permutation = [0; N];
+        for (i, p) in permutation.iter_mut().enumerate() {
+            *p = i;
+        }
+
+        let mut scale = T::ZERO;
+        for column in &a {
+            for &e in column {
+                if e.abs() > scale {
+                    scale = e.abs();
+                }
+            }
+        }
+
+        let mut odd = false;
+        for k in 0..N {
+            let mut pivot = k;
+            for i in k + 1..N {
+                if a[k][i].abs() > a[k][pivot].abs() {
+                    pivot = i;
+                }
+            }
+
+            if pivot != k {
+                for column in &mut a {
+                    column.swap(k, pivot);
+                }
+                permutation.swap(k, pivot);
+                odd = !odd;
+            }
+
+            let d = a[k][k];
+            if d == T::ZERO {
+                continue;
+            }
+
+            let (head, tail) = a.split_at_mut(k + 1);
+            let pivot_column = &mut head[k];
+            for e in &mut pivot_column[k + 1..] {
+                *e /= d;
+            }
+            for column in tail {
+                let u = column[k];
+                for (e, &l) in column[k + 1..].iter_mut().zip(&pivot_column[k + 1..]) {
+                    *e -= l * u;
+                }
+            }
+        }
+
+        Lu {
+            lu: Matrix::from_column_arrays(a),
+            permutation,
+            odd,
+            tolerance: tolerance(N, scale),
+        }
+    }
+}
+
+impl<T, const N: usize> Lu<T, N>
+where
+    T: Num,
+{
+    /// Returns the unit lower triangular factor.
+    pub fn l(&self) -> Matrix<T, N> {
+        let mut l = self.lu;
+        for (j, column) in l.e.iter_mut().enumerate() {
+            for (i, e) in column.iter_mut().enumerate() {
+                if i < j {
+                    *e = T::ZERO;
+                } else if i == j {
+                    *e = T::ONE;
+                }
+            }
+        }
+        l
+    }
+
+    /// Returns the upper triangular factor.
+    pub fn u(&self) -> Matrix<T, N> {
+        let mut u = self.lu;
+        for (j, column) in u.e.iter_mut().enumerate() {
+            for e in &mut column[j + 1..] {
+                *e = T::ZERO;
+            }
+        }
+        u
+    }
+
+    /// Returns the row permutation.
+    ///
+    /// Row `i` of `L * U` is row `permutation[i]` of the matrix.
+    pub const fn permutation(&self) -> [usize; N] {
+        self.permutation
+    }
+
+    /// Returns true if the matrix is singular within the precision.
+    pub fn is_singular(&self) -> bool {
+        (0..N).any(|i| self.lu.e[i][i].abs() <= self.tolerance)
+    }
+
+    /// Returns determinant of the matrix.
+    pub fn determinant(&self) -> T {
+        let mut det = if self.odd { -T::ONE } else { T::ONE };
+        for i in 0..N {
+            det *= self.lu.e[i][i];
+        }
+        det
+    }
+
+    /// Solves `A * x = b` for `x`.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector<T, N>) -> Option<Vector<T, N>> {
+        if self.is_singular() {
+            return None;
+        }
+
+        let a = &self.lu.e;
+        let mut x = Vector::from_array(self.permutation.map(|p| b[p]));
+
+        // Forward substitution with unit diagonal.
+        for j in 0..N {
+            let xj = x[j];
+            for i in j + 1..N {
+                x[i] -= a[j][i] * xj;
+            }
+        }
+
+        // Back substitution.
+        for j in (0..N).rev() {
+            x[j] /= a[j][j];
+            let xj = x[j];
+            for i in 0..j {
+                x[i] -= a[j][i] * xj;
+            }
+        }
+
+        Some(x)
+    }
+
+    /// Returns inverse of the matrix.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Matrix<T, N>> {
+        let mut inverse = Matrix::identity();
+        for column in &mut inverse.e {
+            *column = self.solve(&Vector::from_array(*column))?.into_array();
+        }
+        Some(inverse)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    #[test]
+    fn factors_reconstruct_matrix() {
+        let a = Matrix::from_row_arrays([[0.0, 2.0, 1.0], [1.0, 1.0, 0.0], [4.0, -1.0, 3.0]]);
+        let lu = a.lu();
+        assert!(!lu.is_singular());
+
+        // Column `j` of `L * U` is `L * (U * e_j)`.
+        for (j, a) in a.arrays().iter().enumerate() {
+            let mut e = [0.0; 3];
+            e[j] = 1.0;
+            let column = lu.l() * (lu.u() * Vector::from_array(e));
+            for (&e, &row) in column.array().iter().zip(&lu.permutation()) {
+                assert_close(e, a[row]);
+            }
+        }
+
+        assert_close(lu.determinant(), -11.0);
+
+        let x = Vector::from_array([1.0, -2.0, 0.5]);
+        let b = a * x;
+        let solved = lu.solve(&b).unwrap();
+        let inverted = lu.inverse().unwrap() * b;
+        for ((&s, &i), &x) in solved.array().iter().zip(inverted.array()).zip(x.array()) {
+            assert_close(s, x);
+            assert_close(i, x);
+        }
+    }
+
+    #[test]
+    fn singular_matrix() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let lu = a.lu();
+        assert!(lu.is_singular());
+        assert!(lu.inverse().is_none());
+        assert!(lu.solve(&Vector::from_array([1.0, 2.0, 3.0])).is_none());
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{Num, Vector};
+
+use super::{tolerance, Matrix};
+
+/// QR decomposition computed with Householder reflections.
+///
+/// The matrix with `M` rows and `N <= M` columns is equal to `Q * R`,
+/// where `Q` has orthonormal columns and `R` is upper triangular.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Qr<T, const N: usize, const M: usize> {
+    q: Matrix<T, N, M>,
+    r: Matrix<T, N>,
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Num,
+{
+    /// Computes QR decomposition.
+    ///
+    /// Matrix must have at least as many rows as columns.
+    pub fn qr(&self) -> Qr<T, N, M> {
+        const {
+            assert!(
+                N <= M,
+                "QR decomposition requires at least as many rows as columns"
+            )
+        };
+
+        self.qr_tall()
+    }
+
+    /// Computes QR decomposition without the compile time shape check.
+    ///
+    /// Lets solvers choose the shape at run time, caller makes sure that `N <= M`.
+    pub(super) fn qr_tall(&self) -> Qr<T, N, M> {
+        let mut a = self.e;
+        let mut reflectors = [[T::ZERO; M]; N];
+
+        for (k, v) in reflectors.iter_mut().enumerate() {
+            let mut norm2 = T::ZERO;
+            for &e in &a[k][k..] {
+                norm2 += e * e;
+            }
+            if norm2 == T::ZERO {
+                continue;
+            }
+
+            // Reflect onto the axis pointing away from the column to avoid cancellation.
+            let norm = norm2.sqrt();
+            let alpha = if a[k][k] > T::ZERO { -norm } else { norm };
+            v[k..].copy_from_slice(&a[k][k..]);
+            v[k] -= alpha;
+
+            let mut v2 = T::ZERO;
+            for &e in &v[k..] {
+                v2 += e * e;
+            }
+            let scale = (v2.sqrt()).recip();
+            for e in &mut v[k..] {
+                *e *= scale;
+            }
+
+            for column in &mut a[k..] {
+                reflect(v, column, k);
+            }
+        }
+
+        let mut r = [[T::ZERO; N]; N];
+        for (j, column) in r.iter_mut().enumerate() {
+            column[..=j].copy_from_slice(&a[j][..=j]);
+        }
+
+        let mut q = [[T::ZERO; M]; N];
+        for (j, column) in q.iter_mut().enumerate() {
+            column[j] = T::ONE;
+            for (k, v) in reflectors.iter().enumerate().rev() {
+                reflect(v, column, k);
+            }
+        }
+
+        Qr {
+            q: Matrix::from_column_arrays(q),
+            r: Matrix::from_column_arrays(r),
+        }
+    }
+}
+
+/// Applies reflection `I - 2 * v * v^T` with unit `v` starting at `k` to the column.
+fn reflect<T, const M: usize>(v: &[T; M], column: &mut [T; M], k: usize)
+where
+    T: Num,
+{
+    let mut dot = T::ZERO;
+    for (&v, &c) in v[k..].iter().zip(&column[k..]) {
+        dot += v * c;
+    }
+    let dot = dot * T::TWO;
+    for (&v, c) in v[k..].iter().zip(&mut column[k..]) {
+        *c -= v * dot;
+    }
+}
+
+impl<T, const N: usize, const M: usize> Qr<T, N, M>
+where
+    T: Num,
+{
+    /// Returns the factor with orthonormal columns.
+    pub const fn q(&self) -> Matrix<T, N, M> {
+        self.q
+    }
+
+    /// Returns the upper triangular factor.
+    pub const fn r(&self) -> Matrix<T, N> {
+        self.r
+    }
+
+    /// Returns true if columns of the matrix are linearly dependent within the precision.
+    pub fn is_rank_deficient(&self) -> bool {
+        let r = &self.r.e;
+        let diagonal = |i: usize| r[i][i].abs();
+
+        let mut scale = T::ZERO;
+        for column in r {
+            for &e in column {
+                if e.abs() > scale {
+                    scale = e.abs();
+                }
+            }
+        }
+        let tolerance = tolerance(M, scale);
+        (0..N).any(|i| diagonal(i) <= tolerance)
+    }
+
+    /// Solves `A * x = b` for `x` in the least squares sense.
+    ///
+    /// Returns `None` if columns of the matrix are linearly dependent.
+    pub fn solve(&self, b: &Vector<T, M>) -> Option<Vector<T, N>> {
+        if self.is_rank_deficient() {
+            return None;
+        }
+
+        // x = R^-1 * Q^T * b
+        let mut x = b * self.q;
+        let r = &self.r.e;
+        for j in (0..N).rev() {
+            x[j] /= r[j][j];
+            let xj = x[j];
+            for i in 0..j {
+                x[i] -= r[j][i] * xj;
+            }
+        }
+
+        Some(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstructs_tall_matrix() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]);
+        let qr = a.qr();
+        assert!(!qr.is_rank_deficient());
+
+        for (p, a) in (qr.q() * qr.r()).arrays().iter().zip(a.arrays()) {
+            for (p, a) in p.iter().zip(a) {
+                assert!((p - a).abs() < 1e-12);
+            }
+        }
+
+        let qtq = qr.q().transpose() * qr.q();
+        for (j, column) in qtq.arrays().iter().enumerate() {
+            for (i, &e) in column.iter().enumerate() {
+                assert!((e - if i == j { 1.0 } else { 0.0 }).abs() < 1e-12);
+            }
+        }
+
+        let dependent = Matrix::from_row_arrays([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert!(dependent.qr().is_rank_deficient());
+    }
+}
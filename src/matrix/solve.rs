@@ -0,0 +1,140 @@
+use crate::{Num, Vector};
+
+use super::{tolerance, Matrix};
+
+impl<T, const N: usize> Matrix<T, N>
+where
+    T: Num,
+{
+    /// Solves `A * x = b` for `x` using LU decomposition.
+    ///
+    /// Returns `None` if the matrix is singular.
+    pub fn solve(&self, b: &Vector<T, N>) -> Option<Vector<T, N>> {
+        self.lu().solve(b)
+    }
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Num,
+{
+    /// Finds `x` minimizing `|A * x - b|` using QR decomposition.
+    ///
+    /// Returns `None` if columns of the matrix are linearly dependent.
+    /// For a wide matrix returns the solution with minimal `|x|` instead,
+    /// or `None` if its rows are linearly dependent.
+    pub fn solve_least_squares(&self, b: &Vector<T, M>) -> Option<Vector<T, N>> {
+        if N <= M {
+            self.qr_tall().solve(b)
+        } else {
+            self.pseudo_inverse().map(|p| p * b)
+        }
+    }
+
+    /// Returns Moore-Penrose pseudo-inverse using singular value decomposition.
+    ///
+    /// Returns `None` if columns of the matrix are linearly dependent,
+    /// or rows for a wide matrix.
+    pub fn pseudo_inverse(&self) -> Option<Matrix<T, M, N>> {
+        if N <= M {
+            pseudo_inverse(self)
+        } else {
+            // Pseudo-inverse of the transpose is the transpose of pseudo-inverse.
+            pseudo_inverse(&self.transpose()).map(|p| p.transpose())
+        }
+    }
+}
+
+/// Pseudo-inverse of the matrix with at least as many rows as columns.
+fn pseudo_inverse<T, const N: usize, const M: usize>(a: &Matrix<T, N, M>) -> Option<Matrix<T, M, N>>
+where
+    T: Num,
+{
+    let svd = a.svd_tall();
+
+    // Singular values are sorted, so the first one is the largest.
+    let largest = svd.values.array().first().copied().unwrap_or(T::ZERO);
+    let tolerance = tolerance(M, largest);
+    let mut v = svd.v;
+    for (column, &value) in v.e.iter_mut().zip(svd.values.array()) {
+        if value <= tolerance {
+            return None;
+        }
+
+        let recip = value.recip();
+        for e in column {
+            *e *= recip;
+        }
+    }
+
+    // V * S^-1 * U^T
+    Some(v * svd.u.transpose())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close<const N: usize>(a: [f64; N], b: [f64; N]) {
+        for (a, b) in a.iter().zip(&b) {
+            assert!((a - b).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
+
+    #[test]
+    fn solve_square() {
+        let a = Matrix::from_row_arrays([[2.0, 1.0], [1.0, 3.0]]);
+        let x = a.solve(&Vector::from_array([3.0, 5.0])).unwrap();
+        assert_close(x.into_array(), [0.8, 1.4]);
+
+        let singular = Matrix::from_row_arrays([[1.0, 2.0], [2.0, 4.0]]);
+        assert!(singular.solve(&Vector::from_array([1.0, 2.0])).is_none());
+    }
+
+    #[test]
+    fn least_squares_fits_line() {
+        // y = 1 + 2x through points at x = 0, 1, 2.
+        let a = Matrix::from_row_arrays([[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]]);
+        let x = a
+            .solve_least_squares(&Vector::from_array([1.0, 3.0, 5.0]))
+            .unwrap();
+        assert_close(x.into_array(), [1.0, 2.0]);
+
+        let dependent = Matrix::from_row_arrays([[1.0, 2.0], [1.0, 2.0], [1.0, 2.0]]);
+        assert!(dependent
+            .solve_least_squares(&Vector::from_array([1.0, 2.0, 3.0]))
+            .is_none());
+    }
+
+    #[test]
+    fn least_squares_minimal_norm_for_wide() {
+        // x + y = 2, z = 3.
+        let a = Matrix::from_row_arrays([[1.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let x = a
+            .solve_least_squares(&Vector::from_array([2.0, 3.0]))
+            .unwrap();
+        assert_close(x.into_array(), [1.0, 1.0, 3.0]);
+    }
+
+    #[test]
+    fn pseudo_inverse_known() {
+        let a = Matrix::from_row_arrays([[1.0, 0.0], [0.0, 2.0], [0.0, 0.0]]);
+        let p = a.pseudo_inverse().unwrap();
+        let expected = Matrix::from_row_arrays([[1.0, 0.0, 0.0], [0.0, 0.5, 0.0]]);
+        for (p, e) in p.arrays().iter().zip(expected.arrays()) {
+            assert_close(*p, *e);
+        }
+
+        let wide = a.transpose().pseudo_inverse().unwrap();
+        for (p, e) in wide.arrays().iter().zip(expected.transpose().arrays()) {
+            assert_close(*p, *e);
+        }
+    }
+
+    #[test]
+    fn pseudo_inverse_rank_deficient() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        assert!(a.pseudo_inverse().is_none());
+        assert!(a.transpose().pseudo_inverse().is_none());
+    }
+}
@@ -0,0 +1,153 @@
+use crate::{Num, Vector};
+
+use super::{eigen::descending, Matrix};
+
+/// Singular value decomposition.
+///
+/// The matrix with `M` rows and `N <= M` columns is equal to `U * S * V^T`,
+/// where `S` is diagonal matrix of singular values,
+/// `U` has orthonormal columns and `V` is orthonormal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Svd<T, const N: usize, const M: usize> {
+    /// Left singular vectors in columns.
+    ///
+    /// Columns for zero singular values are zero.
+    pub u: Matrix<T, N, M>,
+
+    /// Singular values in descending order.
+    pub values: Vector<T, N>,
+
+    /// Right singular vectors in columns.
+    pub v: Matrix<T, N>,
+}
+
+impl<T, const N: usize, const M: usize> Matrix<T, N, M>
+where
+    T: Num,
+{
+    /// Computes singular value decomposition with one-sided Jacobi method.
+    ///
+    /// Matrix must have at least as many rows as columns.
+    pub fn svd(&self) -> Svd<T, N, M> {
+        const { assert!(N <= M, "SVD requires at least as many rows as columns") };
+
+        self.svd_tall()
+    }
+
+    /// Computes singular value decomposition without the compile time shape check.
+    ///
+    /// Lets solvers choose the shape at run time, caller makes sure that `N <= M`.
+    pub(super) fn svd_tall(&self) -> Svd<T, N, M> {
+        let mut a = self.e;
+        let mut v = Matrix::<T, N>::identity().e;
+
+        for _ in 0..64 {
+            let mut rotated = false;
+
+            for p in 0..N {
+                for q in p + 1..N {
+                    let (head, tail) = a.split_at_mut(q);
+                    let (ap, aq) = (&mut head[p], &mut tail[0]);
+
+                    let (mut alpha, mut beta, mut gamma) = (T::ZERO, T::ZERO, T::ZERO);
+                    for (&x, &y) in ap.iter().zip(aq.iter()) {
+                        alpha += x * x;
+                        beta += y * y;
+                        gamma += x * y;
+                    }
+
+                    // Columns are already orthogonal.
+                    if gamma.abs() <= T::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (T::TWO * gamma);
+                    let t = (zeta * zeta + T::ONE).sqrt() + zeta.abs();
+                    let t = if zeta < T::ZERO {
+                        -t.recip()
+                    } else {
+                        t.recip()
+                    };
+                    let c = (t * t + T::ONE).sqrt().recip();
+                    let s = t * c;
+
+                    for (x, y) in ap.iter_mut().zip(aq.iter_mut()) {
+                        (*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+                    }
+
+                    let (head, tail) = v.split_at_mut(q);
+                    for (x, y) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+                        (*x, *y) = (c * *x - s * *y, s * *x + c * *y);
+                    }
+                }
+            }
+
+            if !rotated {
+                break;
+            }
+        }
+
+        // Column norms are singular values, normalized columns are left singular vectors.
+        let mut values = [T::ZERO; N];
+        for (value, column) in values.iter_mut().zip(&mut a) {
+            let mut norm2 = T::ZERO;
+            for &e in column.iter() {
+                norm2 += e * e;
+            }
+            *value = norm2.sqrt();
+
+            if *value > T::ZERO {
+                let recip = value.recip();
+                for e in column.iter_mut() {
+                    *e *= recip;
+                }
+            }
+        }
+
+        let order = descending(&values);
+        Svd {
+            u: Matrix::from_column_arrays(order.map(|i| a[i])),
+            values: Vector::from_array(order.map(|i| values[i])),
+            v: Matrix::from_column_arrays(order.map(|i| v[i])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reconstructs_tall_matrix() {
+        let a = Matrix::from_row_arrays([[3.0, 0.0], [0.0, -4.0], [0.0, 0.0]]);
+        let svd = a.svd();
+
+        let values = svd.values.into_array();
+        assert!((values[0] - 4.0).abs() < 1e-12 && (values[1] - 3.0).abs() < 1e-12);
+
+        let b = Matrix::from_row_arrays([[1.0, 2.0], [3.0, 4.0], [5.0, 7.0]]);
+        let svd = b.svd();
+        let mut s = Matrix::<f64, 2>::identity();
+        for (i, column) in s.arrays_mut().iter_mut().enumerate() {
+            column[i] = svd.values[i];
+        }
+        for (p, b) in (svd.u * s * svd.v.transpose())
+            .arrays()
+            .iter()
+            .zip(b.arrays())
+        {
+            for (p, b) in p.iter().zip(b) {
+                assert!((p - b).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn rank_deficient_has_zero_value() {
+        let a = Matrix::from_row_arrays([[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]]);
+        let values = a.svd().values.into_array();
+        assert!((values[0] - 70.0f64.sqrt()).abs() < 1e-12);
+        assert!(values[1].abs() < 1e-12);
+    }
+}
@@ -0,0 +1,48 @@
+//! Elliptic projective geometry of the space.
+//!
+//! Space is closed and has constant positive curvature.
+//! Every line is finite and two points are at most `π / 2` apart.
+
+use crate::Num;
+
+projective!(epga);
+
+/// Returns distance between normalized points from their scalar product.
+///
+/// Returns zero for `cos >= 1`, which coincident points reach by rounding.
+fn arc<T>(cos: T) -> T
+where
+    T: Num,
+{
+    if cos >= T::ONE {
+        T::ZERO
+    } else {
+        cos.acos()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coords_are_gnomonic_coordinates() {
+        let (x, y, z) = Point3::at(3.0, -2.0, 1.0).normalized().coords();
+        assert!((x - 3.0f64).abs() < 1e-12 && (y + 2.0).abs() < 1e-12 && (z - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn translation_moves_along_geodesic() {
+        let moved = Motor3::translation(0.0, 0.5, 0.0).move_point(Point3::ORIGIN);
+        let (x, y, z) = moved.coords();
+        assert!(x.abs() < 1e-12 && (y - 0.5f64.tan()).abs() < 1e-12 && z.abs() < 1e-12);
+        assert!((Point3::ORIGIN.distance(moved) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn distance_is_at_most_half_pi() {
+        let d = Point3::ORIGIN.distance(Point3::new(0.0, 1.0, 0.0, 0.0));
+        assert!((d - core::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert!(Point3::at(1.0, 2.0, 3.0).distance(Point3::at(1.0, 2.0, 3.0)) < 1e-7);
+    }
+}
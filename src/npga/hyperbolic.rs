@@ -0,0 +1,51 @@
+//! Hyperbolic projective geometry of the space.
+//!
+//! Space has constant negative curvature and is modeled by the Klein ball,
+//! so only points `Point3::at(x, y, z)` with `x² + y² + z² < 1` are inside the space.
+//! Points outside the ball are not validated, distances to them come out as zero.
+
+projective!(hpga);
+
+/// Returns distance between normalized points from their scalar product.
+///
+/// Returns zero for `cosh <= 1`. Coincident points get there by rounding,
+/// but so do points outside the Klein ball, which are not validated.
+fn arc<T>(cosh: T) -> T
+where
+    T: NumExp,
+{
+    if cosh <= T::ONE {
+        T::ZERO
+    } else {
+        (cosh + (cosh * cosh - T::ONE).sqrt()).ln()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coords_are_klein_coordinates() {
+        let (x, y, z) = Point3::at(0.3, 0.2, 0.0).normalized().coords();
+        assert!((x - 0.3f64).abs() < 1e-12 && (y - 0.2).abs() < 1e-12 && z.abs() < 1e-12);
+    }
+
+    #[test]
+    fn translation_moves_along_geodesic() {
+        let moved = Motor3::translation(0.5, 0.0, 0.0).move_point(Point3::ORIGIN);
+        let (x, y, z) = moved.coords();
+        assert!((x - 0.5f64.tanh()).abs() < 1e-12 && y.abs() < 1e-12 && z.abs() < 1e-12);
+        assert!((Point3::ORIGIN.distance(moved) - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn distance_from_origin() {
+        let d = Point3::ORIGIN.distance(Point3::at(0.6, 0.0, 0.0));
+        assert!((d - 0.6f64.atanh()).abs() < 1e-12);
+        assert_eq!(
+            Point3::at(0.1, 0.2, 0.3).distance(Point3::at(0.1, 0.2, 0.3)),
+            0.0
+        );
+    }
+}
@@ -0,0 +1,544 @@
+//! Projective geometric algebras of the space with non-degenerate metrics.
+//!
+//! Objects and motors mirror the euclidean ones,
+//! only the metric of the `e0` basis vector differs.
+//! Scalars must implement [`NumExp`](crate::NumExp).
+
+/// Defines `Point3`, `Line3`, `Plane3` and `Motor3` over the elements of the algebra.
+///
+/// Expects `arc` function in scope that returns distance
+/// for the scalar product of normalized points.
+macro_rules! projective {
+    ($elements:ident) => {
+        use core::ops::{BitXor, Mul};
+
+        use crate::{
+            algebra::{
+                $elements::{BiVector, Pseudo, Scalar, TriVector, Vector},
+                Dual,
+            },
+            NumExp,
+        };
+
+        fn regressive<A, B, R>(a: A, b: B) -> R
+        where
+            A: Dual,
+            B: Dual,
+            A::Output: BitXor<B::Output>,
+            <A::Output as BitXor<B::Output>>::Output: Dual<Output = R>,
+        {
+            (a.dual() ^ b.dual()).dual()
+        }
+
+        /// Point in the space.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(transparent)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct Point3<T>(TriVector<T>);
+
+        impl<T> Point3<T>
+        where
+            T: NumExp,
+        {
+            /// Origin point.
+            pub const ORIGIN: Self = Point3(TriVector {
+                e021: T::ZERO,
+                e013: T::ZERO,
+                e032: T::ZERO,
+                e123: T::ONE,
+            });
+
+            /// Creates a new point at the given coordinates.
+            pub const fn at(x: T, y: T, z: T) -> Self {
+                Point3(TriVector {
+                    e021: z,
+                    e013: y,
+                    e032: x,
+                    e123: T::ONE,
+                })
+            }
+
+            /// Creates a new point from projective vector elements.
+            pub const fn new(e123: T, e032: T, e013: T, e021: T) -> Self {
+                Point3(TriVector {
+                    e021,
+                    e013,
+                    e032,
+                    e123,
+                })
+            }
+
+            /// Returns the coordinates of the point.
+            ///
+            /// Coordinates are divided by the `e123` weight,
+            /// so they match [`at`](Self::at) regardless of normalization.
+            /// Points with zero weight return their components as is.
+            pub fn coords(&self) -> (T, T, T) {
+                let w = self.0.e123;
+                if w == T::ZERO {
+                    (self.0.e032, self.0.e013, self.0.e021)
+                } else {
+                    (self.0.e032 / w, self.0.e013 / w, self.0.e021 / w)
+                }
+            }
+
+            /// Returns norm of the point.
+            pub fn norm(&self) -> T {
+                self.0.norm()
+            }
+
+            /// Normalizes the point.
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            /// Returns same point, but normalized.
+            pub fn normalized(&self) -> Self {
+                let norm = self.norm();
+                if norm == T::ZERO {
+                    *self
+                } else {
+                    Point3(self.0 / norm)
+                }
+            }
+
+            /// Returns distance between two points along the geodesic.
+            ///
+            /// Points are not validated, see the module documentation.
+            pub fn distance(&self, other: Point3<T>) -> T {
+                let (s, _) = self.0 * !other.0;
+                arc(s.0.abs() / (self.norm() * other.norm()))
+            }
+
+            /// Finds the line through two points.
+            ///
+            /// Line is directed from `self` to `other`.
+            pub fn join(&self, other: Point3<T>) -> Line3<T> {
+                Line3(regressive(self.0, other.0))
+            }
+
+            /// Finds the plane through three points.
+            pub fn join3(&self, other: Point3<T>, another: Point3<T>) -> Plane3<T> {
+                self.join(other).join(another)
+            }
+        }
+
+        /// Line in the space.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(transparent)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct Line3<T>(BiVector<T>);
+
+        impl<T> Line3<T>
+        where
+            T: NumExp,
+        {
+            /// Creates a new line from projective vector elements.
+            pub const fn new(e01: T, e02: T, e03: T, e12: T, e31: T, e23: T) -> Self {
+                Line3(BiVector {
+                    e01,
+                    e02,
+                    e03,
+                    e12,
+                    e31,
+                    e23,
+                })
+            }
+
+            /// Returns norm of the line.
+            pub fn norm(&self) -> T {
+                self.0.norm()
+            }
+
+            /// Returns squared norm of the line.
+            pub fn norm2(&self) -> T {
+                self.0.norm2()
+            }
+
+            /// Normalizes the line.
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            /// Returns same line, but normalized.
+            pub fn normalized(&self) -> Self {
+                let norm = self.norm();
+                if norm == T::ZERO {
+                    *self
+                } else {
+                    Line3(self.0 / norm)
+                }
+            }
+
+            /// Finds the plane through the line and the point.
+            pub fn join(&self, point: Point3<T>) -> Plane3<T> {
+                Plane3(regressive(self.0, point.0))
+            }
+
+            /// Finds the point where the line intersects the plane.
+            pub fn meet(&self, plane: Plane3<T>) -> Point3<T> {
+                Point3(self.0 ^ plane.0)
+            }
+        }
+
+        /// Plane in the space.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(transparent)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(transparent))]
+        pub struct Plane3<T>(Vector<T>);
+
+        impl<T> Plane3<T>
+        where
+            T: NumExp,
+        {
+            /// Creates a new plane from projective vector elements.
+            pub const fn new(e0: T, e1: T, e2: T, e3: T) -> Self {
+                Plane3(Vector { e0, e1, e2, e3 })
+            }
+
+            /// Creates a plane through three points.
+            ///
+            /// Same as [`Point3::join3`].
+            pub fn through(p: Point3<T>, q: Point3<T>, r: Point3<T>) -> Self {
+                p.join3(q, r)
+            }
+
+            /// Returns norm of the plane.
+            pub fn norm(&self) -> T {
+                self.0.norm()
+            }
+
+            /// Returns squared norm of the plane.
+            pub fn norm2(&self) -> T {
+                self.0.norm2()
+            }
+
+            /// Normalizes the plane.
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            /// Returns same plane, but normalized.
+            pub fn normalized(&self) -> Self {
+                let norm = self.norm();
+                if norm == T::ZERO {
+                    *self
+                } else {
+                    Plane3(self.0 / norm)
+                }
+            }
+
+            /// Finds the line where two planes intersect.
+            pub fn meet(&self, other: Plane3<T>) -> Line3<T> {
+                Line3(self.0 ^ other.0)
+            }
+        }
+
+        /// Motor is a 3D rigid motion operator.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct Motor3<T> {
+            scalar: Scalar<T>,
+            bivector: BiVector<T>,
+            pseudo: Pseudo<T>,
+        }
+
+        impl<T> Motor3<T> {
+            /// Creates a new motor from the given scalar, bivector and pseudoscalar.
+            pub const fn new(scalar: Scalar<T>, bivector: BiVector<T>, pseudo: Pseudo<T>) -> Self {
+                Motor3 {
+                    scalar,
+                    bivector,
+                    pseudo,
+                }
+            }
+        }
+
+        impl<T> Motor3<T>
+        where
+            T: NumExp,
+        {
+            /// Returns the scalar part of this motor.
+            pub const fn scalar(&self) -> Scalar<T> {
+                self.scalar
+            }
+
+            /// Returns the bivector part of this motor.
+            pub const fn bivector(&self) -> BiVector<T> {
+                self.bivector
+            }
+
+            /// Identity motor that leaves everything in place.
+            pub const IDENTITY: Self = Motor3 {
+                scalar: Scalar(T::ONE),
+                bivector: BiVector::ZERO,
+                pseudo: Pseudo::ZERO,
+            };
+
+            /// Returns exponent of the simple bivector.
+            fn exp(bivector: BiVector<T>) -> Self {
+                let (s, _, _) = bivector * bivector;
+                let (scalar, factor) = if s.0 < T::ZERO {
+                    let angle = (-s.0).sqrt();
+                    let (sin, cos) = angle.sin_cos();
+                    (cos, sin / angle)
+                } else if s.0 > T::ZERO {
+                    let angle = s.0.sqrt();
+                    let (exp, rexp) = (angle.exp(), (-angle).exp());
+                    ((exp + rexp) * T::HALF, (exp - rexp) * T::HALF / angle)
+                } else {
+                    (T::ONE, T::ONE)
+                };
+
+                Motor3 {
+                    scalar: Scalar(scalar),
+                    bivector: bivector * factor,
+                    pseudo: Pseudo::ZERO,
+                }
+            }
+
+            /// Creates a rotation around the line by the angle in radians.
+            ///
+            /// Rotation follows the right-hand rule around the line direction.
+            /// Line `a.join(b)` is directed from `a` to `b`.
+            pub fn rotation(axis: Line3<T>, angle: T) -> Self {
+                Motor3::exp(axis.normalized().0 * (-angle * T::HALF))
+            }
+
+            /// Creates a translation of the origin by the given displacement.
+            ///
+            /// Origin is moved along the geodesic by the length of the displacement.
+            pub fn translation(dx: T, dy: T, dz: T) -> Self {
+                Motor3::exp(BiVector {
+                    e01: -dx * T::HALF,
+                    e02: -dy * T::HALF,
+                    e03: -dz * T::HALF,
+                    e12: T::ZERO,
+                    e31: T::ZERO,
+                    e23: T::ZERO,
+                })
+            }
+
+            /// Creates a new motor that moves point `a` to point `b` along the geodesic.
+            pub fn point_point(a: Point3<T>, b: Point3<T>) -> Self {
+                let a = a.normalized().0;
+                let (square, _) = a * a;
+                let (scalar, bivector) = b.normalized().0 * a;
+
+                let double = Motor3 {
+                    scalar: scalar / square.0,
+                    bivector: bivector / square.0,
+                    pseudo: Pseudo::ZERO,
+                };
+
+                double.normalized().sqrt()
+            }
+
+            /// Creates a new motor that moves line `a` to line `b`.
+            pub fn line_line(a: Line3<T>, b: Line3<T>) -> Self {
+                let a = a.normalized().0;
+                let (square, _, _) = a * a;
+                let (scalar, bivector, pseudo) = b.normalized().0 * a;
+
+                let double = Motor3 {
+                    scalar: scalar / square.0,
+                    bivector: bivector / square.0,
+                    pseudo: pseudo / square.0,
+                };
+
+                double.normalized().sqrt()
+            }
+
+            /// Creates a new motor that moves plane `a` to plane `b`.
+            pub fn plane_plane(a: Plane3<T>, b: Plane3<T>) -> Self {
+                let a = a.normalized().0;
+                let (square, _) = a * a;
+                let (scalar, bivector) = b.normalized().0 * a;
+
+                let double = Motor3 {
+                    scalar: scalar / square.0,
+                    bivector: bivector / square.0,
+                    pseudo: Pseudo::ZERO,
+                };
+
+                double.normalized().sqrt()
+            }
+
+            /// Moves the given point by this motor.
+            pub fn move_point(&self, point: Point3<T>) -> Point3<T> {
+                let tv0 = self.scalar * point.0;
+                let (v0, tv1) = self.bivector * point.0;
+                let v1 = self.pseudo * point.0;
+                let v = v0 + v1;
+                let tv = tv0 + tv1;
+
+                let (_, a) = v * !self.bivector;
+                let b = tv * !self.scalar;
+                let (_, c) = tv * !self.bivector;
+                let d = v * !self.pseudo;
+
+                Point3(a + b + c + d).normalized()
+            }
+
+            /// Moves the given line by this motor.
+            pub fn move_line(&self, line: Line3<T>) -> Line3<T> {
+                let bv0 = self.scalar * line.0;
+                let (s, bv1, p) = self.bivector * line.0;
+                let bv2 = self.pseudo * line.0;
+                let bv = bv0 + bv1 + bv2;
+
+                let a = s * !self.bivector;
+                let b = bv * !self.scalar;
+                let (_, c, _) = bv * !self.bivector;
+                let d = p * !self.bivector;
+                let e = bv * !self.pseudo;
+
+                Line3(a + b + c + d + e).normalized()
+            }
+
+            /// Moves the given plane by this motor.
+            pub fn move_plane(&self, plane: Plane3<T>) -> Plane3<T> {
+                let v0 = self.scalar * plane.0;
+                let (v1, tv0) = self.bivector * plane.0;
+                let tv1 = self.pseudo * plane.0;
+                let v = v0 + v1;
+                let tv = tv0 + tv1;
+
+                let a = v * !self.scalar;
+                let (b, _) = v * !self.bivector;
+                let (c, _) = tv * !self.bivector;
+                let d = tv * !self.pseudo;
+
+                Plane3(a + b + c + d).normalized()
+            }
+
+            /// Returns the reverse of this motor.
+            ///
+            /// For normalized motors this is the inverse motion.
+            pub fn reverse(&self) -> Self {
+                Motor3 {
+                    scalar: self.scalar,
+                    bivector: !self.bivector,
+                    pseudo: self.pseudo,
+                }
+            }
+
+            /// Returns the inverse of this motor.
+            ///
+            /// Unlike [`reverse`](Self::reverse) it does not require the motor to be normalized.
+            /// Returns `None` if the motor is degenerate and has no inverse.
+            pub fn inverse(&self) -> Option<Self> {
+                let r = self.reverse();
+                let (a, b) = self.squared();
+
+                // Inverse of `a + b * e0123` is `(a - b * e0123) / (a² - b² * e0123²)`.
+                let i2 = Pseudo::new(T::ONE) * Pseudo::new(T::ONE);
+                let det = a * a - b * b * i2.0;
+                if det == T::ZERO {
+                    return None;
+                }
+                let c = a / det;
+                let d = Pseudo::new(-b / det);
+
+                Some(Motor3 {
+                    scalar: r.scalar * c + r.pseudo * d,
+                    bivector: r.bivector * c + r.bivector * d,
+                    pseudo: r.pseudo * c + r.scalar * d,
+                })
+            }
+
+            /// Returns this motor relative to the other one.
+            ///
+            /// The result `r` satisfies `other * r == self`.
+            /// Returns `None` if the other motor has no inverse.
+            pub fn relative_to(&self, other: &Self) -> Option<Self> {
+                other.inverse().map(|inverse| inverse * *self)
+            }
+
+            /// Returns motor times its reverse as `a + b * e0123`.
+            fn squared(&self) -> (T, T) {
+                let (s, _, p) = self.bivector * !self.bivector;
+                let pp = self.pseudo * self.pseudo;
+                let a = self.scalar.0 * self.scalar.0 + s.0 + pp.0;
+                let b = T::TWO * self.scalar.0 * self.pseudo.e0123 + p.e0123;
+                (a, b)
+            }
+
+            /// Returns norm of the motor.
+            pub fn norm(&self) -> T {
+                self.norm2().abs().sqrt()
+            }
+
+            /// Returns squared norm of the motor.
+            pub fn norm2(&self) -> T {
+                self.squared().0
+            }
+
+            /// Normalizes the motor.
+            pub fn normalize(&mut self) {
+                *self = self.normalized();
+            }
+
+            /// Returns a normalized motor.
+            pub fn normalized(&self) -> Self {
+                let norm = self.norm();
+                if norm == T::ZERO {
+                    *self
+                } else {
+                    Motor3 {
+                        scalar: self.scalar / norm,
+                        bivector: self.bivector / norm,
+                        pseudo: self.pseudo / norm,
+                    }
+                }
+            }
+
+            /// Halves the motor.
+            ///
+            /// Motor must be simple, e.g. pure rotation or translation.
+            pub fn sqrt(&self) -> Self {
+                Motor3 {
+                    scalar: Scalar(self.scalar.0 + T::ONE),
+                    bivector: self.bivector,
+                    pseudo: self.pseudo,
+                }
+                .normalized()
+            }
+        }
+
+        impl<T> Mul<Motor3<T>> for Motor3<T>
+        where
+            T: NumExp,
+        {
+            type Output = Motor3<T>;
+
+            fn mul(self, rhs: Motor3<T>) -> Motor3<T> {
+                let s0 = self.scalar * rhs.scalar;
+                let bv0 = self.scalar * rhs.bivector;
+                let p0 = self.scalar * rhs.pseudo;
+
+                let bv1 = self.bivector * rhs.scalar;
+                let (s1, bv2, p1) = self.bivector * rhs.bivector;
+                let bv4 = self.bivector * rhs.pseudo;
+
+                let p2 = self.pseudo * rhs.scalar;
+                let bv3 = self.pseudo * rhs.bivector;
+                let s2 = self.pseudo * rhs.pseudo;
+
+                Motor3 {
+                    scalar: s0 + s1 + s2,
+                    bivector: bv0 + bv1 + bv2 + bv3 + bv4,
+                    pseudo: p0 + p1 + p2,
+                }
+                .normalized()
+            }
+        }
+    };
+}
+
+pub mod elliptic;
+pub mod hyperbolic;
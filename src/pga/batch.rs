@@ -0,0 +1,338 @@
+use crate::{Matrix, Num};
+
+use super::{
+    elements::{BiVector2, BiVector3, TriVector3, Vector2, Vector3},
+    Line2, Line3, Motor2, Motor3, Plane3, Point2, Point3,
+};
+
+/// Linear map of the sandwich product over element coefficients.
+///
+/// Columns are images of the basis elements.
+pub(super) struct Sandwich<T, const N: usize>([[T; N]; N]);
+
+impl<T, const N: usize> Sandwich<T, N>
+where
+    T: Num,
+{
+    pub(super) fn new(f: impl Fn([T; N]) -> [T; N]) -> Self {
+        let mut columns = [[T::ZERO; N]; N];
+        for (i, column) in columns.iter_mut().enumerate() {
+            let mut basis = [T::ZERO; N];
+            basis[i] = T::ONE;
+            *column = f(basis);
+        }
+        Sandwich(columns)
+    }
+
+    #[inline(always)]
+    fn apply(&self, e: [T; N]) -> [T; N] {
+        let mut r = [T::ZERO; N];
+        for (column, &e) in self.0.iter().zip(&e) {
+            for (r, &c) in r.iter_mut().zip(column) {
+                *r += c * e;
+            }
+        }
+        r
+    }
+
+    pub(super) fn into_matrix(self) -> Matrix<T, N> {
+        Matrix::from_column_arrays(self.0)
+    }
+}
+
+pub(super) fn bivector2<T>([e01, e20, e12]: [T; 3]) -> BiVector2<T> {
+    BiVector2 { e01, e20, e12 }
+}
+
+pub(super) fn bivector2_array<T>(bv: BiVector2<T>) -> [T; 3] {
+    [bv.e01, bv.e20, bv.e12]
+}
+
+fn vector2<T>([e0, e1, e2]: [T; 3]) -> Vector2<T> {
+    Vector2 { e0, e1, e2 }
+}
+
+fn vector2_array<T>(v: Vector2<T>) -> [T; 3] {
+    [v.e0, v.e1, v.e2]
+}
+
+fn trivector3<T>([e021, e013, e032, e123]: [T; 4]) -> TriVector3<T> {
+    TriVector3 {
+        e021,
+        e013,
+        e032,
+        e123,
+    }
+}
+
+fn trivector3_array<T>(tv: TriVector3<T>) -> [T; 4] {
+    [tv.e021, tv.e013, tv.e032, tv.e123]
+}
+
+pub(super) fn bivector3<T>([e01, e02, e03, e12, e31, e23]: [T; 6]) -> BiVector3<T> {
+    BiVector3 {
+        e01,
+        e02,
+        e03,
+        e12,
+        e31,
+        e23,
+    }
+}
+
+pub(super) fn bivector3_array<T>(bv: BiVector3<T>) -> [T; 6] {
+    [bv.e01, bv.e02, bv.e03, bv.e12, bv.e31, bv.e23]
+}
+
+fn vector3<T>([e0, e1, e2, e3]: [T; 4]) -> Vector3<T> {
+    Vector3 { e0, e1, e2, e3 }
+}
+
+fn vector3_array<T>(v: Vector3<T>) -> [T; 4] {
+    [v.e0, v.e1, v.e2, v.e3]
+}
+
+impl<T> Motor2<T>
+where
+    T: Num,
+{
+    /// Moves all points in place by this motor.
+    ///
+    /// Same as calling [`move_point`](Self::move_point) for each point,
+    /// but motor-dependent work is done once.
+    pub fn move_points(&self, points: &mut [Point2<T>]) {
+        let m = Sandwich::new(|e| bivector2_array(self.sandwich_point(bivector2(e))));
+        for point in points {
+            let e = m.apply(bivector2_array(point.bivector()));
+            *point = Point2::from_bivector(bivector2(e).normalized());
+        }
+    }
+
+    /// Moves points from `src` by this motor and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn move_points_into(&self, src: &[Point2<T>], dst: &mut [Point2<T>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        let m = Sandwich::new(|e| bivector2_array(self.sandwich_point(bivector2(e))));
+        for (point, out) in src.iter().zip(dst) {
+            let e = m.apply(bivector2_array(point.bivector()));
+            *out = Point2::from_bivector(bivector2(e).normalized());
+        }
+    }
+
+    /// Moves all lines in place by this motor.
+    ///
+    /// Same as calling [`move_line`](Self::move_line) for each line,
+    /// but motor-dependent work is done once.
+    pub fn move_lines(&self, lines: &mut [Line2<T>]) {
+        let m = Sandwich::new(|e| vector2_array(self.sandwich_line(vector2(e))));
+        for line in lines {
+            let e = m.apply(vector2_array(line.vector()));
+            *line = Line2::from_vector(vector2(e).normalized());
+        }
+    }
+
+    /// Moves lines from `src` by this motor and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn move_lines_into(&self, src: &[Line2<T>], dst: &mut [Line2<T>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        let m = Sandwich::new(|e| vector2_array(self.sandwich_line(vector2(e))));
+        for (line, out) in src.iter().zip(dst) {
+            let e = m.apply(vector2_array(line.vector()));
+            *out = Line2::from_vector(vector2(e).normalized());
+        }
+    }
+}
+
+impl<T> Motor3<T>
+where
+    T: Num,
+{
+    /// Moves all points in place by this motor.
+    ///
+    /// Same as calling [`move_point`](Self::move_point) for each point,
+    /// but motor-dependent work is done once.
+    pub fn move_points(&self, points: &mut [Point3<T>]) {
+        let m = Sandwich::new(|e| trivector3_array(self.sandwich_point(trivector3(e))));
+        for point in points {
+            let e = m.apply(trivector3_array(point.trivector()));
+            *point = Point3::from_trivector(trivector3(e).normalized());
+        }
+    }
+
+    /// Moves points from `src` by this motor and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn move_points_into(&self, src: &[Point3<T>], dst: &mut [Point3<T>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        let m = Sandwich::new(|e| trivector3_array(self.sandwich_point(trivector3(e))));
+        for (point, out) in src.iter().zip(dst) {
+            let e = m.apply(trivector3_array(point.trivector()));
+            *out = Point3::from_trivector(trivector3(e).normalized());
+        }
+    }
+
+    /// Moves all lines in place by this motor.
+    ///
+    /// Same as calling [`move_line`](Self::move_line) for each line,
+    /// but motor-dependent work is done once.
+    pub fn move_lines(&self, lines: &mut [Line3<T>]) {
+        let m = Sandwich::new(|e| bivector3_array(self.sandwich_line(bivector3(e))));
+        for line in lines {
+            let e = m.apply(bivector3_array(line.bivector()));
+            *line = Line3::from_bivector(bivector3(e).normalized());
+        }
+    }
+
+    /// Moves lines from `src` by this motor and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn move_lines_into(&self, src: &[Line3<T>], dst: &mut [Line3<T>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        let m = Sandwich::new(|e| bivector3_array(self.sandwich_line(bivector3(e))));
+        for (line, out) in src.iter().zip(dst) {
+            let e = m.apply(bivector3_array(line.bivector()));
+            *out = Line3::from_bivector(bivector3(e).normalized());
+        }
+    }
+
+    /// Moves all planes in place by this motor.
+    ///
+    /// Same as calling [`move_plane`](Self::move_plane) for each plane,
+    /// but motor-dependent work is done once.
+    pub fn move_planes(&self, planes: &mut [Plane3<T>]) {
+        let m = Sandwich::new(|e| vector3_array(self.sandwich_plane(vector3(e))));
+        for plane in planes {
+            let e = m.apply(vector3_array(plane.vector()));
+            *plane = Plane3::from_vector(vector3(e).normalized());
+        }
+    }
+
+    /// Moves planes from `src` by this motor and writes them to `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn move_planes_into(&self, src: &[Plane3<T>], dst: &mut [Plane3<T>]) {
+        assert_eq!(src.len(), dst.len(), "Slices must have the same length");
+
+        let m = Sandwich::new(|e| vector3_array(self.sandwich_plane(vector3(e))));
+        for (plane, out) in src.iter().zip(dst) {
+            let e = m.apply(vector3_array(plane.vector()));
+            *out = Plane3::from_vector(vector3(e).normalized());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Matrix4, Vector};
+
+    fn assert_arrays<const N: usize>(a: [f64; N], b: [f64; N]) {
+        for (a, b) in a.iter().zip(&b) {
+            assert!((a - b).abs() < 1e-12, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn batch_matches_single_motor2() {
+        let m = Motor2::rotation_about(Point2::at(1.0, -1.0), 0.8) * Motor2::translation(2.0, 0.5);
+        let points = [Point2::at(0.0, 0.0), Point2::at(3.0, -2.0)];
+        let lines = [Line2::new(1.0, 2.0, 3.0), Line2::new(-1.0, 0.0, 1.0)];
+
+        let mut moved = points;
+        m.move_points(&mut moved);
+        for (&p, q) in points.iter().zip(moved) {
+            assert_arrays(
+                bivector2_array(m.move_point(p).bivector()),
+                bivector2_array(q.bivector()),
+            );
+        }
+
+        let mut moved = [Line2::new(0.0, 0.0, 0.0); 2];
+        m.move_lines_into(&lines, &mut moved);
+        for (&l, k) in lines.iter().zip(moved) {
+            assert_arrays(
+                vector2_array(m.move_line(l).vector()),
+                vector2_array(k.vector()),
+            );
+        }
+    }
+
+    #[test]
+    fn batch_matches_single_motor3() {
+        let axis = Point3::at(1.0, 0.0, 0.0).join(Point3::at(1.0, 2.0, 3.0));
+        let m = Motor3::translation(0.0, 0.0, 2.0) * Motor3::rotation(axis, 0.9);
+        let points = [Point3::at(0.0, 0.0, 0.0), Point3::at(3.0, -2.0, 1.0)];
+        let lines = [points[0].join(points[1]), axis];
+        let planes = [
+            Plane3::new(1.0, 0.0, 0.0, 1.0),
+            Plane3::new(-2.0, 1.0, 1.0, 0.0),
+        ];
+
+        let mut moved = [Point3::at(0.0, 0.0, 0.0); 2];
+        m.move_points_into(&points, &mut moved);
+        for (&p, q) in points.iter().zip(moved) {
+            assert_arrays(
+                trivector3_array(m.move_point(p).trivector()),
+                trivector3_array(q.trivector()),
+            );
+        }
+
+        let mut moved = lines;
+        m.move_lines(&mut moved);
+        for (&l, k) in lines.iter().zip(moved) {
+            assert_arrays(
+                bivector3_array(m.move_line(l).bivector()),
+                bivector3_array(k.bivector()),
+            );
+        }
+
+        let mut moved = planes;
+        m.move_planes(&mut moved);
+        for (&p, q) in planes.iter().zip(moved) {
+            assert_arrays(
+                vector3_array(m.move_plane(p).vector()),
+                vector3_array(q.vector()),
+            );
+        }
+    }
+
+    #[test]
+    fn matrix_transforms_points_and_vectors() {
+        let m = Matrix4::from_row_arrays([
+            [0.0, -1.0, 0.0, 1.0],
+            [1.0, 0.0, 0.0, 2.0],
+            [0.0, 0.0, 1.0, 3.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ]);
+        let src = [
+            Vector::from_array([1.0, 0.0, 0.0]),
+            Vector::from_array([0.0, 2.0, -1.0]),
+        ];
+
+        let mut points = [Vector::from_array([0.0; 3]); 2];
+        m.transform_points_into(&src, &mut points);
+        assert_arrays(points[0].into_array(), [1.0, 3.0, 3.0]);
+        assert_arrays(points[1].into_array(), [-1.0, 2.0, 2.0]);
+
+        let mut vectors = src;
+        m.transform_vectors(&mut vectors);
+        assert_arrays(vectors[0].into_array(), [0.0, 1.0, 0.0]);
+        assert_arrays(vectors[1].into_array(), [-2.0, 0.0, -1.0]);
+    }
+}
@@ -0,0 +1,339 @@
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::Num;
+
+use super::{
+    elements::{BiVector2, TriVector3},
+    Point2, Point3,
+};
+
+/// Two dimensional direction.
+///
+/// Same as an ideal point, but can be added to points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Direction2<T>(BiVector2<T>);
+
+impl<T> Direction2<T>
+where
+    T: Num,
+{
+    pub(super) const fn bivector(&self) -> BiVector2<T> {
+        self.0
+    }
+
+    pub(super) const fn from_bivector(bivector: BiVector2<T>) -> Self {
+        Direction2(bivector)
+    }
+
+    /// Zero direction.
+    pub const ZERO: Self = Direction2(BiVector2 {
+        e01: T::ZERO,
+        e20: T::ZERO,
+        e12: T::ZERO,
+    });
+
+    /// Creates a new direction with the given components.
+    pub const fn new(x: T, y: T) -> Self {
+        Direction2(BiVector2 {
+            e01: y,
+            e20: x,
+            e12: T::ZERO,
+        })
+    }
+
+    /// Returns components of the direction.
+    pub const fn coords(&self) -> (T, T) {
+        (self.0.e20, self.0.e01)
+    }
+
+    /// Returns squared length of the direction.
+    pub fn norm2(&self) -> T {
+        self.0.e20 * self.0.e20 + self.0.e01 * self.0.e01
+    }
+
+    /// Returns length of the direction.
+    pub fn norm(&self) -> T {
+        self.norm2().sqrt()
+    }
+
+    /// Normalizes the direction to unit length.
+    ///
+    /// Does not affect zero direction.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns the direction with unit length.
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        if norm == T::ZERO {
+            *self
+        } else {
+            Direction2(self.0 / norm)
+        }
+    }
+}
+
+impl<T> From<Direction2<T>> for Point2<T>
+where
+    T: Num,
+{
+    fn from(direction: Direction2<T>) -> Self {
+        Point2::from_bivector(direction.0)
+    }
+}
+
+impl<T> Add for Direction2<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Direction2(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Direction2<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Direction2(self.0 - rhs.0)
+    }
+}
+
+impl<T> Neg for Direction2<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Direction2(-self.0)
+    }
+}
+
+impl<T> Mul<T> for Direction2<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Direction2(self.0 * rhs)
+    }
+}
+
+impl<T> Add<Direction2<T>> for Point2<T>
+where
+    T: Num,
+{
+    type Output = Point2<T>;
+
+    /// Moves the point along the direction.
+    ///
+    /// Ideal point stays ideal and its direction is offset.
+    fn add(self, rhs: Direction2<T>) -> Point2<T> {
+        Point2::from_bivector(self.unitized() + rhs.0)
+    }
+}
+
+impl<T> Sub<Direction2<T>> for Point2<T>
+where
+    T: Num,
+{
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Direction2<T>) -> Point2<T> {
+        Point2::from_bivector(self.unitized() - rhs.0)
+    }
+}
+
+impl<T> Sub for Point2<T>
+where
+    T: Num,
+{
+    type Output = Direction2<T>;
+
+    /// Returns direction from `rhs` to `self`.
+    ///
+    /// If one of the points is ideal, returns direction towards it.
+    fn sub(self, rhs: Point2<T>) -> Direction2<T> {
+        let d = match (self.is_ideal(), rhs.is_ideal()) {
+            (false, true) => -rhs.bivector(),
+            (true, false) => self.bivector(),
+            _ => self.unitized() - rhs.unitized(),
+        };
+        Direction2::new(d.e20, d.e01)
+    }
+}
+
+/// Three dimensional direction.
+///
+/// Same as an ideal point, but can be added to points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Direction3<T>(TriVector3<T>);
+
+impl<T> Direction3<T>
+where
+    T: Num,
+{
+    pub(super) const fn trivector(&self) -> TriVector3<T> {
+        self.0
+    }
+
+    pub(super) const fn from_trivector(trivector: TriVector3<T>) -> Self {
+        Direction3(trivector)
+    }
+
+    /// Zero direction.
+    pub const ZERO: Self = Direction3(TriVector3::ZERO);
+
+    /// Creates a new direction with the given components.
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Direction3(TriVector3 {
+            e021: z,
+            e013: y,
+            e032: x,
+            e123: T::ZERO,
+        })
+    }
+
+    /// Returns components of the direction.
+    pub const fn coords(&self) -> (T, T, T) {
+        (self.0.e032, self.0.e013, self.0.e021)
+    }
+
+    /// Returns squared length of the direction.
+    pub fn norm2(&self) -> T {
+        self.0.e032 * self.0.e032 + self.0.e013 * self.0.e013 + self.0.e021 * self.0.e021
+    }
+
+    /// Returns length of the direction.
+    pub fn norm(&self) -> T {
+        self.norm2().sqrt()
+    }
+
+    /// Normalizes the direction to unit length.
+    ///
+    /// Does not affect zero direction.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns the direction with unit length.
+    pub fn normalized(&self) -> Self {
+        let norm = self.norm();
+        if norm == T::ZERO {
+            *self
+        } else {
+            Direction3(self.0 * norm.recip())
+        }
+    }
+}
+
+impl<T> From<Direction3<T>> for Point3<T>
+where
+    T: Num,
+{
+    fn from(direction: Direction3<T>) -> Self {
+        Point3::from_trivector(direction.0)
+    }
+}
+
+impl<T> Add for Direction3<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Direction3(self.0 + rhs.0)
+    }
+}
+
+impl<T> Sub for Direction3<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Direction3(self.0 - rhs.0)
+    }
+}
+
+impl<T> Neg for Direction3<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Direction3(-self.0)
+    }
+}
+
+impl<T> Mul<T> for Direction3<T>
+where
+    T: Num,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self {
+        Direction3(self.0 * rhs)
+    }
+}
+
+impl<T> Add<Direction3<T>> for Point3<T>
+where
+    T: Num,
+{
+    type Output = Point3<T>;
+
+    /// Moves the point along the direction.
+    ///
+    /// Ideal point stays ideal and its direction is offset.
+    fn add(self, rhs: Direction3<T>) -> Point3<T> {
+        Point3::from_trivector(self.unitized() + rhs.0)
+    }
+}
+
+impl<T> Sub<Direction3<T>> for Point3<T>
+where
+    T: Num,
+{
+    type Output = Point3<T>;
+
+    fn sub(self, rhs: Direction3<T>) -> Point3<T> {
+        Point3::from_trivector(self.unitized() - rhs.0)
+    }
+}
+
+impl<T> Sub for Point3<T>
+where
+    T: Num,
+{
+    type Output = Direction3<T>;
+
+    /// Returns direction from `rhs` to `self`.
+    ///
+    /// If one of the points is ideal, returns direction towards it.
+    fn sub(self, rhs: Point3<T>) -> Direction3<T> {
+        let d = match (self.is_ideal(), rhs.is_ideal()) {
+            (false, true) => -rhs.trivector(),
+            (true, false) => self.trivector(),
+            _ => self.unitized() - rhs.unitized(),
+        };
+        Direction3::new(d.e032, d.e013, d.e021)
+    }
+}
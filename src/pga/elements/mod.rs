@@ -6,18 +6,17 @@ mod scalar;
 mod trivector;
 mod vector;
 
-use core::ops::BitXor;
+use core::ops::{BitAnd, BitXor};
 
-pub(crate) use self::{bivector::*, pseudo::*, scalar::*, trivector::*, vector::*};
+use crate::Num;
 
-/// A trait for duality operations.
-pub trait Dual {
-    /// Dual type of the element.
-    type Output;
+pub(crate) use crate::algebra::Dual;
 
-    /// Returns the dual of the element.
-    fn dual(self) -> Self::Output;
-}
+pub(crate) use self::{bivector::*, pseudo::*, scalar::*, trivector::*, vector::*};
+
+// Geometric, inner and outer products generated from the algebra signatures.
+include!(concat!(env!("OUT_DIR"), "/pga2.rs"));
+include!(concat!(env!("OUT_DIR"), "/pga3.rs"));
 
 pub fn regressive<T, U, R>(lhs: T, rhs: U) -> R
 where
@@ -40,3 +39,176 @@ where
 {
     (a.dual() ^ b.dual() ^ c.dual()).dual()
 }
+
+macro_rules! impl_regressive {
+    ($($lhs:ident & $rhs:ident => $out:ident,)*) => {$(
+        impl<T> BitAnd<$rhs<T>> for $lhs<T>
+        where
+            T: Num,
+        {
+            type Output = $out<T>;
+
+            /// Regressive product, also known as join.
+            #[inline]
+            fn bitand(self, rhs: $rhs<T>) -> $out<T> {
+                regressive(self, rhs)
+            }
+        }
+    )*};
+}
+
+impl_regressive! {
+    Scalar2 & Pseudo2 => Scalar2,
+    Vector2 & BiVector2 => Scalar2,
+    Vector2 & Pseudo2 => Vector2,
+    BiVector2 & Vector2 => Scalar2,
+    BiVector2 & BiVector2 => Vector2,
+    BiVector2 & Pseudo2 => BiVector2,
+    Pseudo2 & Scalar2 => Scalar2,
+    Pseudo2 & Vector2 => Vector2,
+    Pseudo2 & BiVector2 => BiVector2,
+    Pseudo2 & Pseudo2 => Pseudo2,
+
+    Scalar3 & Pseudo3 => Scalar3,
+    Vector3 & TriVector3 => Scalar3,
+    Vector3 & Pseudo3 => Vector3,
+    XBiVector3 & EBiVector3 => Scalar3,
+    XBiVector3 & BiVector3 => Scalar3,
+    XBiVector3 & TriVector3 => Vector3,
+    XBiVector3 & Pseudo3 => XBiVector3,
+    EBiVector3 & XBiVector3 => Scalar3,
+    EBiVector3 & BiVector3 => Scalar3,
+    EBiVector3 & TriVector3 => Vector3,
+    EBiVector3 & Pseudo3 => EBiVector3,
+    BiVector3 & XBiVector3 => Scalar3,
+    BiVector3 & EBiVector3 => Scalar3,
+    BiVector3 & BiVector3 => Scalar3,
+    BiVector3 & TriVector3 => Vector3,
+    BiVector3 & Pseudo3 => BiVector3,
+    TriVector3 & Vector3 => Scalar3,
+    TriVector3 & XBiVector3 => Vector3,
+    TriVector3 & EBiVector3 => Vector3,
+    TriVector3 & BiVector3 => Vector3,
+    TriVector3 & TriVector3 => BiVector3,
+    TriVector3 & Pseudo3 => TriVector3,
+    Pseudo3 & Scalar3 => Scalar3,
+    Pseudo3 & Vector3 => Vector3,
+    Pseudo3 & XBiVector3 => XBiVector3,
+    Pseudo3 & EBiVector3 => EBiVector3,
+    Pseudo3 & BiVector3 => BiVector3,
+    Pseudo3 & TriVector3 => TriVector3,
+    Pseudo3 & Pseudo3 => Pseudo3,
+}
+
+macro_rules! sign {
+    (+, $e:expr) => {
+        $e
+    };
+    (-, $e:expr) => {
+        -$e
+    };
+}
+
+macro_rules! impl_involutions {
+    ($($ty:ident: $reverse:tt $involution:tt $conjugate:tt,)*) => {$(
+        impl<T> $ty<T>
+        where
+            T: Num,
+        {
+            /// Returns the reverse of the element.
+            ///
+            /// Negates grades 2 and 3, same as `!`.
+            #[inline]
+            pub fn reverse(self) -> Self {
+                sign!($reverse, self)
+            }
+
+            /// Returns the grade involution of the element.
+            ///
+            /// Negates odd grades.
+            #[inline]
+            pub fn grade_involution(self) -> Self {
+                sign!($involution, self)
+            }
+
+            /// Returns the Clifford conjugate of the element.
+            ///
+            /// Negates grades 1 and 2, same as reverse of the grade involution.
+            #[inline]
+            pub fn conjugate(self) -> Self {
+                sign!($conjugate, self)
+            }
+        }
+    )*};
+}
+
+impl_involutions! {
+    Scalar2: + + +,
+    Vector2: + - -,
+    BiVector2: - + -,
+    Pseudo2: - - +,
+    Scalar3: + + +,
+    Vector3: + - -,
+    XBiVector3: - + -,
+    EBiVector3: - + -,
+    BiVector3: - + -,
+    TriVector3: - - +,
+    Pseudo3: + + +,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pseudoscalar_is_regressive_identity() {
+        let i2 = Pseudo2 { e012: 1.0 };
+        let i3 = Pseudo3 { e0123: 1.0 };
+
+        assert_eq!(Scalar2(2.0) & i2, Scalar2(2.0));
+        assert_eq!(Scalar3(2.0) & i3, Scalar3(2.0));
+
+        let e = EBiVector3::new(1.0, 2.0, 3.0);
+        assert_eq!(e & i3, e);
+    }
+
+    #[test]
+    fn involutions_by_grade() {
+        let v = Vector3::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(v.reverse(), v);
+        assert_eq!(v.grade_involution(), -v);
+        assert_eq!(v.conjugate(), -v);
+
+        let t = TriVector3::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(t.reverse(), !t);
+        assert_eq!(t.conjugate(), t.reverse().grade_involution());
+    }
+
+    #[test]
+    fn vector_times_euclidean_pseudo() {
+        // e1 * e123 = e23, e2 * e123 = e31 and e3 * e123 = e12.
+        let v = Vector3 {
+            e0: 0.0,
+            e1: 1.0,
+            e2: 2.0,
+            e3: 3.0,
+        };
+        let t = TriVector3 {
+            e021: 0.0,
+            e013: 0.0,
+            e032: 0.0,
+            e123: 1.0,
+        };
+        let expected = BiVector3 {
+            e01: 0.0,
+            e02: 0.0,
+            e03: 0.0,
+            e12: 3.0,
+            e31: 2.0,
+            e23: 1.0,
+        };
+
+        assert_eq!(v | t, expected);
+        assert_eq!(v * t, (expected, Pseudo3 { e0123: 0.0 }));
+    }
+}
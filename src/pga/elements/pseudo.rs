@@ -2,10 +2,7 @@ use core::ops::{Add, BitOr, Div, DivAssign, Mul, MulAssign, Neg, Not, Sub};
 
 use crate::Num;
 
-use super::{
-    BiVector2, BiVector3, Dual, EBiVector3, Scalar2, Scalar3, TriVector3, Vector2, Vector3,
-    XBiVector3,
-};
+use super::{Dual, Scalar2, Scalar3};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(transparent)]
@@ -134,62 +131,6 @@ where
     }
 }
 
-impl<T> BitOr<Scalar2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = Pseudo2<T>;
-
-    fn bitor(self, other: Scalar2<T>) -> Pseudo2<T> {
-        Pseudo2 {
-            e012: self.e012 * other.0,
-        }
-    }
-}
-
-impl<T> BitOr<Vector2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = BiVector2<T>;
-
-    fn bitor(self, other: Vector2<T>) -> BiVector2<T> {
-        BiVector2 {
-            e01: self.e012 * other.e2,
-            e20: self.e012 * other.e1,
-            e12: T::ZERO,
-        }
-    }
-}
-
-impl<T> BitOr<BiVector2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = Vector2<T>;
-
-    fn bitor(self, other: BiVector2<T>) -> Vector2<T> {
-        Vector2 {
-            e0: -(self.e012 * other.e12),
-            e1: T::ZERO,
-            e2: T::ZERO,
-        }
-    }
-}
-
-impl<T> Mul<Scalar2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = Pseudo2<T>;
-
-    fn mul(self, other: Scalar2<T>) -> Pseudo2<T> {
-        Pseudo2 {
-            e012: self.e012 * other.0,
-        }
-    }
-}
-
 impl<T> MulAssign<Scalar2<T>> for Pseudo2<T>
 where
     T: Num,
@@ -199,36 +140,6 @@ where
     }
 }
 
-impl<T> Mul<Vector2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = BiVector2<T>;
-
-    fn mul(self, other: Vector2<T>) -> BiVector2<T> {
-        BiVector2 {
-            e01: self.e012 * other.e2,
-            e20: self.e012 * other.e1,
-            e12: T::ZERO,
-        }
-    }
-}
-
-impl<T> Mul<BiVector2<T>> for Pseudo2<T>
-where
-    T: Num,
-{
-    type Output = Vector2<T>;
-
-    fn mul(self, other: BiVector2<T>) -> Vector2<T> {
-        Vector2 {
-            e0: -(self.e012 * other.e12),
-            e1: T::ZERO,
-            e2: T::ZERO,
-        }
-    }
-}
-
 impl<T> Div<Scalar2<T>> for Pseudo2<T>
 where
     T: Num,
@@ -391,81 +302,6 @@ where
     }
 }
 
-impl<T> BitOr<Vector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = TriVector3<T>;
-
-    fn bitor(self, other: Vector3<T>) -> TriVector3<T> {
-        TriVector3 {
-            e021: -(self.e0123 * other.e3),
-            e013: -(self.e0123 * other.e2),
-            e032: -(self.e0123 * other.e1),
-            e123: T::ZERO,
-        }
-    }
-}
-
-impl<T> BitOr<EBiVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = XBiVector3<T>;
-
-    fn bitor(self, other: EBiVector3<T>) -> XBiVector3<T> {
-        XBiVector3 {
-            e01: -(self.e0123 * other.e23),
-            e02: -(self.e0123 * other.e31),
-            e03: -(self.e0123 * other.e12),
-        }
-    }
-}
-
-impl<T> BitOr<BiVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = XBiVector3<T>;
-
-    fn bitor(self, other: BiVector3<T>) -> XBiVector3<T> {
-        XBiVector3 {
-            e01: -(self.e0123 * other.e23),
-            e02: -(self.e0123 * other.e31),
-            e03: -(self.e0123 * other.e12),
-        }
-    }
-}
-
-impl<T> BitOr<TriVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = Vector3<T>;
-
-    fn bitor(self, other: TriVector3<T>) -> Vector3<T> {
-        Vector3 {
-            e0: -(self.e0123 * other.e123),
-            e1: T::ZERO,
-            e2: T::ZERO,
-            e3: T::ZERO,
-        }
-    }
-}
-
-impl<T> Mul<Scalar3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = Pseudo3<T>;
-
-    fn mul(self, other: Scalar3<T>) -> Pseudo3<T> {
-        Pseudo3 {
-            e0123: self.e0123 * other.0,
-        }
-    }
-}
-
 impl<T> MulAssign<Scalar3<T>> for Pseudo3<T>
 where
     T: Num,
@@ -475,68 +311,6 @@ where
     }
 }
 
-impl<T> Mul<Vector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = TriVector3<T>;
-
-    fn mul(self, other: Vector3<T>) -> TriVector3<T> {
-        TriVector3 {
-            e021: -(self.e0123 * other.e3),
-            e013: -(self.e0123 * other.e2),
-            e032: -(self.e0123 * other.e1),
-            e123: T::ZERO,
-        }
-    }
-}
-
-impl<T> Mul<EBiVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = XBiVector3<T>;
-
-    fn mul(self, other: EBiVector3<T>) -> XBiVector3<T> {
-        XBiVector3 {
-            e01: -(self.e0123 * other.e23),
-            e02: -(self.e0123 * other.e31),
-            e03: -(self.e0123 * other.e12),
-        }
-    }
-}
-
-impl<T> Mul<BiVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = XBiVector3<T>;
-
-    fn mul(self, other: BiVector3<T>) -> XBiVector3<T> {
-        XBiVector3 {
-            e01: -(self.e0123 * other.e23),
-            e02: -(self.e0123 * other.e31),
-            e03: -(self.e0123 * other.e12),
-        }
-    }
-}
-
-impl<T> Mul<TriVector3<T>> for Pseudo3<T>
-where
-    T: Num,
-{
-    type Output = Vector3<T>;
-
-    fn mul(self, other: TriVector3<T>) -> Vector3<T> {
-        Vector3 {
-            e0: -(self.e0123 * other.e123),
-            e1: T::ZERO,
-            e2: T::ZERO,
-            e3: T::ZERO,
-        }
-    }
-}
-
 impl<T> Div<Scalar3<T>> for Pseudo3<T>
 where
     T: Num,
@@ -0,0 +1,189 @@
+use crate::{Matrix, Num, Vector};
+
+use super::Motor3;
+
+/// Error-state Kalman filter tracking a rigid body motion.
+///
+/// State is a pose and a constant velocity bivector in the body frame,
+/// so after time `dt` the pose becomes `pose * Motor3::exp(velocity * dt)`.
+/// Errors are bivectors `[e01, e02, e03, e12, e31, e23]` applied on the right,
+/// i.e. the true pose is `pose * Motor3::exp(error)`.
+/// Covariance is over the pose error followed by the velocity error.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Eskf3<T> {
+    /// Estimated pose.
+    pub pose: Motor3<T>,
+
+    /// Estimated velocity bivector in the body frame.
+    pub velocity: Vector<T, 6>,
+
+    /// Covariance of the error state.
+    pub covariance: Matrix<T, 12>,
+}
+
+impl<T> Eskf3<T>
+where
+    T: Num,
+{
+    /// Creates a filter with the given initial state.
+    pub const fn new(pose: Motor3<T>, velocity: Vector<T, 6>, covariance: Matrix<T, 12>) -> Self {
+        Eskf3 {
+            pose,
+            velocity,
+            covariance,
+        }
+    }
+
+    /// Propagates the state by the time step.
+    ///
+    /// `noise` is the process noise covariance accumulated over the step.
+    pub fn predict(&mut self, dt: T, noise: &Matrix<T, 12>) {
+        let step = Vector::from_array(self.velocity.into_array().map(|v| v * dt));
+        let motion = Motor3::exp(step);
+
+        // Error moves into the new body frame and picks up the velocity error.
+        let adjoint = motion.reverse().adjoint();
+        let jacobian = Motor3::right_jacobian(step);
+
+        let mut f = Matrix::<T, 12>::identity();
+        let columns = f.arrays_mut();
+        for c in 0..6 {
+            columns[c][..6].copy_from_slice(&adjoint.arrays()[c]);
+            for (e, &j) in columns[c + 6][..6].iter_mut().zip(&jacobian.arrays()[c]) {
+                *e = j * dt;
+            }
+        }
+
+        self.pose = self.pose * motion;
+        self.covariance = f * self.covariance * f.transpose() + *noise;
+    }
+
+    /// Corrects the state with a measurement.
+    ///
+    /// `residual` is the measurement minus its prediction,
+    /// `jacobian` maps the error state to the measurement
+    /// and `noise` is the measurement covariance.
+    ///
+    /// Returns false and leaves the state unchanged
+    /// if the innovation covariance is singular.
+    pub fn update<const M: usize>(
+        &mut self,
+        residual: Vector<T, M>,
+        jacobian: &Matrix<T, 12, M>,
+        noise: &Matrix<T, M>,
+    ) -> bool {
+        let h = *jacobian;
+        let ph = self.covariance * h.transpose();
+        let Some(s) = (h * ph + *noise).lu().inverse() else {
+            return false;
+        };
+
+        let k = ph * s;
+        let error = k * residual;
+
+        // Joseph form keeps the covariance symmetric and positive.
+        let ikh = Matrix::identity() - k * h;
+        self.covariance = ikh * self.covariance * ikh.transpose() + k * *noise * k.transpose();
+
+        let [e0, e1, e2, e3, e4, e5, v @ ..] = error.into_array();
+        self.pose = self.pose * Motor3::exp(Vector::from_array([e0, e1, e2, e3, e4, e5]));
+        for (velocity, v) in self.velocity.array_mut().iter_mut().zip(v) {
+            *velocity += v;
+        }
+
+        true
+    }
+
+    /// Corrects the state with a measured pose.
+    ///
+    /// Measurement error is applied on the right, same as the state error.
+    ///
+    /// Returns false and leaves the state unchanged
+    /// if the estimated pose is degenerate or the innovation covariance is singular.
+    pub fn update_pose(&mut self, measured: Motor3<T>, noise: &Matrix<T, 6>) -> bool {
+        let Some(relative) = measured.relative_to(&self.pose) else {
+            return false;
+        };
+        let residual = relative.log();
+
+        let mut h = Matrix::<T, 12, 6>::from_column_arrays([[T::ZERO; 6]; 12]);
+        let inverse = Motor3::left_jacobian_inverse(residual);
+        h.arrays_mut()[..6].copy_from_slice(inverse.arrays());
+
+        self.update(residual, &h, noise)
+    }
+
+    /// Corrects the state with a measured velocity bivector in the body frame.
+    pub fn update_velocity(&mut self, measured: Vector<T, 6>, noise: &Matrix<T, 6>) -> bool {
+        let mut residual = measured;
+        for (r, &v) in residual.array_mut().iter_mut().zip(self.velocity.array()) {
+            *r -= v;
+        }
+
+        let mut h = Matrix::<T, 12, 6>::from_column_arrays([[T::ZERO; 6]; 12]);
+        for (i, column) in h.arrays_mut()[6..].iter_mut().enumerate() {
+            column[i] = T::ONE;
+        }
+
+        self.update(residual, &h, noise)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point3;
+
+    fn diagonal<const N: usize>(value: f64) -> Matrix<f64, N> {
+        let mut m = Matrix::identity();
+        for (i, column) in m.arrays_mut().iter_mut().enumerate() {
+            column[i] = value;
+        }
+        m
+    }
+
+    #[test]
+    fn tracks_constant_velocity() {
+        let velocity = Vector::from_array([0.5, -0.2, 0.1, 0.3, 0.0, -0.1]);
+        let dt = 0.1;
+        let step = Motor3::exp(Vector::from_array(velocity.into_array().map(|v| v * dt)));
+
+        let mut truth = Motor3::translation(1.0, 2.0, 3.0);
+        let mut filter = Eskf3::new(truth, Vector::from_array([0.0; 6]), diagonal(1.0));
+
+        for _ in 0..100 {
+            truth = truth * step;
+            filter.predict(dt, &diagonal(1e-6));
+            assert!(filter.update_pose(truth, &diagonal(1e-4)));
+        }
+
+        for (v, e) in filter
+            .velocity
+            .into_array()
+            .iter()
+            .zip(velocity.into_array())
+        {
+            assert!((v - e).abs() < 1e-3, "{:?}", filter.velocity);
+        }
+
+        let p = Point3::at(1.0, -1.0, 0.5);
+        let (x, y, z) = filter.pose.move_point(p).coords();
+        let (ex, ey, ez) = truth.move_point(p).coords();
+        assert!((x - ex).abs() < 1e-3 && (y - ey).abs() < 1e-3 && (z - ez).abs() < 1e-3);
+    }
+
+    #[test]
+    fn singular_innovation_is_rejected() {
+        let mut filter = Eskf3::new(
+            Motor3::IDENTITY,
+            Vector::from_array([0.0; 6]),
+            diagonal(0.0),
+        );
+        let before = filter;
+
+        let measured = Vector::from_array([1.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+        assert!(!filter.update_velocity(measured, &diagonal(0.0)));
+        assert_eq!(filter, before);
+    }
+}
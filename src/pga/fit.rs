@@ -0,0 +1,212 @@
+use crate::{Matrix4, Num, Quaternion};
+
+use super::{Motor2, Motor3, Point2, Point3};
+
+impl<T> Motor2<T>
+where
+    T: Num,
+{
+    /// Finds the motor that best moves points `a` to points `b`
+    /// in the least squares sense.
+    ///
+    /// Optional weights scale the contribution of each correspondence.
+    /// Returns identity motor if total weight is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn fit(a: &[Point2<T>], b: &[Point2<T>], weights: Option<&[T]>) -> Self {
+        assert_eq!(a.len(), b.len(), "Point sets must have the same length");
+        if let Some(weights) = weights {
+            assert_eq!(a.len(), weights.len(), "Weights must match points");
+        }
+
+        let weight = |i: usize| weights.map_or(T::ONE, |w| w[i]);
+
+        let mut total = T::ZERO;
+        let (mut ax, mut ay, mut bx, mut by) = (T::ZERO, T::ZERO, T::ZERO, T::ZERO);
+        for i in 0..a.len() {
+            let w = weight(i);
+            let (x, y) = a[i].normalized().coords();
+            let (u, v) = b[i].normalized().coords();
+            total += w;
+            ax += w * x;
+            ay += w * y;
+            bx += w * u;
+            by += w * v;
+        }
+
+        if total == T::ZERO {
+            return Motor2::IDENTITY;
+        }
+
+        let (ax, ay, bx, by) = (ax / total, ay / total, bx / total, by / total);
+
+        let (mut cos, mut sin) = (T::ZERO, T::ZERO);
+        for i in 0..a.len() {
+            let w = weight(i);
+            let (x, y) = a[i].normalized().coords();
+            let (u, v) = b[i].normalized().coords();
+            let (x, y, u, v) = (x - ax, y - ay, u - bx, v - by);
+            cos += w * (x * u + y * v);
+            sin += w * (x * v - y * u);
+        }
+
+        let angle = sin.atan2(cos);
+        let (s, c) = angle.sin_cos();
+
+        // Translation moves rotated centroid of `a` to centroid of `b`.
+        let dx = bx - (c * ax - s * ay);
+        let dy = by - (s * ax + c * ay);
+
+        Motor2::translation(dx, dy) * Motor2::rotation_about(Point2::ORIGIN, angle)
+    }
+}
+
+impl<T> Motor3<T>
+where
+    T: Num,
+{
+    /// Finds the motor that best moves points `a` to points `b`
+    /// in the least squares sense.
+    ///
+    /// Uses Horn's closed form solution with unit quaternions.
+    /// Optional weights scale the contribution of each correspondence.
+    /// Returns identity motor if total weight is zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn fit(a: &[Point3<T>], b: &[Point3<T>], weights: Option<&[T]>) -> Self {
+        assert_eq!(a.len(), b.len(), "Point sets must have the same length");
+        if let Some(weights) = weights {
+            assert_eq!(a.len(), weights.len(), "Weights must match points");
+        }
+
+        let weight = |i: usize| weights.map_or(T::ONE, |w| w[i]);
+        let coords = |p: Point3<T>| {
+            let (x, y, z) = p.normalized().coords();
+            [x, y, z]
+        };
+
+        let mut total = T::ZERO;
+        let mut ca = [T::ZERO; 3];
+        let mut cb = [T::ZERO; 3];
+        for i in 0..a.len() {
+            let w = weight(i);
+            let (pa, pb) = (coords(a[i]), coords(b[i]));
+            total += w;
+            for k in 0..3 {
+                ca[k] += w * pa[k];
+                cb[k] += w * pb[k];
+            }
+        }
+
+        if total == T::ZERO {
+            return Motor3::IDENTITY;
+        }
+
+        let ca = ca.map(|v| v / total);
+        let cb = cb.map(|v| v / total);
+
+        // Cross-covariance of centered point sets.
+        let mut s = [[T::ZERO; 3]; 3];
+        for i in 0..a.len() {
+            let w = weight(i);
+            let (pa, pb) = (coords(a[i]), coords(b[i]));
+            for (j, row) in s.iter_mut().enumerate() {
+                for (k, e) in row.iter_mut().enumerate() {
+                    *e += w * (pa[j] - ca[j]) * (pb[k] - cb[k]);
+                }
+            }
+        }
+
+        let [[sxx, sxy, sxz], [syx, syy, syz], [szx, szy, szz]] = s;
+
+        // Rotation quaternion is the eigenvector of the largest eigenvalue.
+        let n = [
+            [sxx + syy + szz, syz - szy, szx - sxz, sxy - syx],
+            [syz - szy, sxx - syy - szz, sxy + syx, szx + sxz],
+            [szx - sxz, sxy + syx, syy - sxx - szz, syz + szy],
+            [sxy - syx, szx + sxz, syz + szy, szz - sxx - syy],
+        ];
+
+        let eigen = Matrix4::from_column_arrays(n).symmetric_eigen();
+        let [w, x, y, z] = eigen.vectors.arrays()[0];
+
+        let q = Quaternion::new(x, y, z, w).normalized();
+
+        // Translation moves rotated centroid of `a` to centroid of `b`.
+        let m = q.to_matrix();
+        let r = m.arrays();
+        let mut t = cb;
+        for (k, tk) in t.iter_mut().enumerate() {
+            *tk -= r[0][k] * ca[0] + r[1][k] * ca[1] + r[2][k] * ca[2];
+        }
+
+        Motor3::translation(t[0], t[1], t[2]) * Motor3::from(q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn fit_recovers_motor2() {
+        let m = Motor2::rotation_about(Point2::at(1.0, 2.0), 0.6) * Motor2::translation(0.5, -1.0);
+        let a = [
+            Point2::at(0.0, 0.0),
+            Point2::at(3.0, 1.0),
+            Point2::at(-1.0, 2.0),
+            Point2::at(2.0, -4.0),
+        ];
+        let b = a.map(|p| m.move_point(p));
+
+        let fit = Motor2::fit(&a, &b, None);
+        for (&p, &q) in a.iter().zip(&b) {
+            let (x, y) = fit.move_point(p).normalized().coords();
+            let (ex, ey) = q.normalized().coords();
+            assert_close(x, ex);
+            assert_close(y, ey);
+        }
+    }
+
+    #[test]
+    fn fit_recovers_motor3() {
+        let axis = Point3::at(1.0, 0.0, 0.0).join(Point3::at(2.0, 1.0, 3.0));
+        let m = Motor3::translation(0.5, -1.0, 2.0) * Motor3::rotation(axis, 1.1);
+        let mut a = [
+            Point3::at(0.0, 0.0, 0.0),
+            Point3::at(3.0, 1.0, 0.0),
+            Point3::at(-1.0, 2.0, 1.0),
+            Point3::at(2.0, -4.0, 5.0),
+            Point3::at(1.0, 1.0, 1.0),
+        ];
+        let b = a.map(|p| m.move_point(p));
+
+        // Zero weight hides the broken correspondence.
+        a[4] = Point3::at(10.0, -10.0, 10.0);
+        let weights = [1.0, 2.0, 0.5, 1.0, 0.0];
+
+        let fit = Motor3::fit(&a, &b, Some(&weights));
+        for (&p, &q) in a.iter().zip(&b).take(4) {
+            let (x, y, z) = fit.move_point(p).normalized().coords();
+            let (ex, ey, ez) = q.normalized().coords();
+            assert_close(x, ex);
+            assert_close(y, ey);
+            assert_close(z, ez);
+        }
+    }
+
+    #[test]
+    fn fit_without_weight_is_identity() {
+        let a = [Point3::at(1.0, 2.0, 3.0)];
+        let b = [Point3::at(4.0, 5.0, 6.0)];
+        assert_eq!(Motor3::fit(&a, &b, Some(&[0.0])), Motor3::IDENTITY);
+    }
+}
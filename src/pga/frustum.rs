@@ -0,0 +1,282 @@
+use crate::{Matrix4, Num};
+
+use super::{Motor3, Plane3, Point3};
+
+/// Result of classifying a volume against a frustum.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Containment {
+    /// Volume is completely outside.
+    Outside,
+
+    /// Volume crosses the boundary.
+    Intersecting,
+
+    /// Volume is completely inside.
+    Inside,
+}
+
+/// Frustum is a convex volume bounded by six planes.
+///
+/// Planes are oriented so that points inside the frustum
+/// are on the positive side of every plane.
+/// Planes are stored in order: left, right, bottom, top, near, far.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frustum3<T> {
+    planes: [Plane3<T>; 6],
+}
+
+impl<T> Frustum3<T>
+where
+    T: Num,
+{
+    /// Creates a new frustum from the given planes.
+    ///
+    /// Planes are expected in order: left, right, bottom, top, near, far.
+    /// Planes must face inside the frustum.
+    pub fn new(planes: [Plane3<T>; 6]) -> Self {
+        Frustum3 {
+            planes: planes.map(|plane| plane.normalized()),
+        }
+    }
+
+    /// Extracts frustum from the view-projection matrix.
+    ///
+    /// Clip space is expected to have depth in range `[0, 1]`.
+    /// The resulting frustum is in the space the matrix transforms from.
+    pub fn from_matrix(matrix: &Matrix4<T>) -> Self {
+        let e = matrix.arrays();
+        let row = |m: usize| [e[0][m], e[1][m], e[2][m], e[3][m]];
+
+        let [x, y, z, w] = [row(0), row(1), row(2), row(3)];
+
+        let sum =
+            |a: [T; 4], b: [T; 4]| Plane3::new(a[3] + b[3], a[0] + b[0], a[1] + b[1], a[2] + b[2]);
+        let diff =
+            |a: [T; 4], b: [T; 4]| Plane3::new(a[3] - b[3], a[0] - b[0], a[1] - b[1], a[2] - b[2]);
+
+        Frustum3::new([
+            sum(w, x),
+            diff(w, x),
+            sum(w, y),
+            diff(w, y),
+            Plane3::new(z[3], z[0], z[1], z[2]),
+            diff(w, z),
+        ])
+    }
+
+    /// Creates perspective frustum in view space.
+    ///
+    /// Camera is at the origin looking along Z axis with Y axis up.
+    /// `fovy` is vertical field of view in radians.
+    pub fn perspective(fovy: T, aspect: T, near: T, far: T) -> Self {
+        let ty = (fovy * T::HALF).tan();
+        let tx = ty * aspect;
+
+        Frustum3::new([
+            Plane3::new(T::ZERO, T::ONE, T::ZERO, tx),
+            Plane3::new(T::ZERO, -T::ONE, T::ZERO, tx),
+            Plane3::new(T::ZERO, T::ZERO, T::ONE, ty),
+            Plane3::new(T::ZERO, T::ZERO, -T::ONE, ty),
+            Plane3::new(-near, T::ZERO, T::ZERO, T::ONE),
+            Plane3::new(far, T::ZERO, T::ZERO, -T::ONE),
+        ])
+    }
+
+    /// Creates orthographic frustum in view space.
+    ///
+    /// Camera is at the origin looking along Z axis with Y axis up.
+    pub fn orthographic(left: T, right: T, bottom: T, top: T, near: T, far: T) -> Self {
+        Frustum3::new([
+            Plane3::new(-left, T::ONE, T::ZERO, T::ZERO),
+            Plane3::new(right, -T::ONE, T::ZERO, T::ZERO),
+            Plane3::new(-bottom, T::ZERO, T::ONE, T::ZERO),
+            Plane3::new(top, T::ZERO, -T::ONE, T::ZERO),
+            Plane3::new(-near, T::ZERO, T::ZERO, T::ONE),
+            Plane3::new(far, T::ZERO, T::ZERO, -T::ONE),
+        ])
+    }
+
+    /// Returns planes of the frustum.
+    ///
+    /// Planes are in order: left, right, bottom, top, near, far.
+    pub const fn planes(&self) -> &[Plane3<T>; 6] {
+        &self.planes
+    }
+
+    /// Returns the frustum moved by the motor.
+    pub fn transformed(&self, motor: &Motor3<T>) -> Self {
+        Frustum3 {
+            planes: self.planes.map(|plane| motor.move_plane(plane)),
+        }
+    }
+
+    /// Moves the frustum by the motor.
+    pub fn transform(&mut self, motor: &Motor3<T>) {
+        *self = self.transformed(motor);
+    }
+
+    /// Returns true if the point is inside the frustum.
+    pub fn contains_point(&self, point: Point3<T>) -> bool {
+        self.planes
+            .iter()
+            .all(|plane| plane.signed_distance(point) >= T::ZERO)
+    }
+
+    /// Classifies the sphere against the frustum.
+    pub fn classify_sphere(&self, center: Point3<T>, radius: T) -> Containment {
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let d = plane.signed_distance(center);
+            if d < -radius {
+                return Containment::Outside;
+            }
+            if d < radius {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns true if the sphere is completely inside the frustum.
+    pub fn contains_sphere(&self, center: Point3<T>, radius: T) -> bool {
+        self.classify_sphere(center, radius) == Containment::Inside
+    }
+
+    /// Returns true if the sphere is at least partially inside the frustum.
+    ///
+    /// This test is conservative and may report intersection
+    /// for spheres near frustum corners.
+    pub fn intersects_sphere(&self, center: Point3<T>, radius: T) -> bool {
+        self.classify_sphere(center, radius) != Containment::Outside
+    }
+
+    /// Classifies the axis-aligned box against the frustum.
+    pub fn classify_aabb(&self, min: Point3<T>, max: Point3<T>) -> Containment {
+        let (x0, y0, z0) = min.normalized().coords();
+        let (x1, y1, z1) = max.normalized().coords();
+
+        let center = Point3::at(
+            (x0 + x1) * T::HALF,
+            (y0 + y1) * T::HALF,
+            (z0 + z1) * T::HALF,
+        );
+        let (hx, hy, hz) = (
+            (x1 - x0) * T::HALF,
+            (y1 - y0) * T::HALF,
+            (z1 - z0) * T::HALF,
+        );
+
+        let mut result = Containment::Inside;
+        for plane in &self.planes {
+            let v = plane.vector();
+            let d = plane.signed_distance(center);
+            let r = hx * v.e1.abs() + hy * v.e2.abs() + hz * v.e3.abs();
+
+            if d < -r {
+                return Containment::Outside;
+            }
+            if d < r {
+                result = Containment::Intersecting;
+            }
+        }
+        result
+    }
+
+    /// Returns true if the axis-aligned box is completely inside the frustum.
+    pub fn contains_aabb(&self, min: Point3<T>, max: Point3<T>) -> bool {
+        self.classify_aabb(min, max) == Containment::Inside
+    }
+
+    /// Returns true if the axis-aligned box is at least partially inside the frustum.
+    ///
+    /// This test is conservative and may report intersection
+    /// for boxes near frustum corners.
+    pub fn intersects_aabb(&self, min: Point3<T>, max: Point3<T>) -> bool {
+        self.classify_aabb(min, max) != Containment::Outside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frustum() -> Frustum3<f64> {
+        Frustum3::perspective(core::f64::consts::FRAC_PI_2, 1.0, 1.0, 10.0)
+    }
+
+    #[test]
+    fn points() {
+        let frustum = frustum();
+        assert!(frustum.contains_point(Point3::at(0.0, 0.0, 5.0)));
+        assert!(frustum.contains_point(Point3::at(4.0, -4.0, 5.0)));
+        assert!(!frustum.contains_point(Point3::at(0.0, 0.0, 0.5)));
+        assert!(!frustum.contains_point(Point3::at(0.0, 0.0, 11.0)));
+        assert!(!frustum.contains_point(Point3::at(6.0, 0.0, 5.0)));
+        assert!(!frustum.contains_point(Point3::at(0.0, -6.0, 5.0)));
+    }
+
+    #[test]
+    fn spheres() {
+        let frustum = frustum();
+        assert_eq!(
+            frustum.classify_sphere(Point3::at(0.0, 0.0, 5.0), 1.0),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.classify_sphere(Point3::at(5.0, 0.0, 5.0), 1.0),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.classify_sphere(Point3::at(0.0, 0.0, 12.0), 1.0),
+            Containment::Outside
+        );
+        assert!(frustum.intersects_sphere(Point3::at(0.0, 0.0, 0.5), 1.0));
+        assert!(!frustum.contains_sphere(Point3::at(0.0, 0.0, 0.5), 1.0));
+    }
+
+    #[test]
+    fn boxes() {
+        let frustum = frustum();
+        assert_eq!(
+            frustum.classify_aabb(Point3::at(-1.0, -1.0, 4.0), Point3::at(1.0, 1.0, 6.0)),
+            Containment::Inside
+        );
+        assert_eq!(
+            frustum.classify_aabb(Point3::at(-1.0, -1.0, 9.0), Point3::at(1.0, 1.0, 11.0)),
+            Containment::Intersecting
+        );
+        assert_eq!(
+            frustum.classify_aabb(Point3::at(20.0, -1.0, 4.0), Point3::at(22.0, 1.0, 6.0)),
+            Containment::Outside
+        );
+    }
+
+    #[test]
+    fn from_matrix_matches_perspective() {
+        let (near, far) = (1.0, 10.0);
+        let matrix = Matrix4::from_column_arrays([
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, far / (far - near), 1.0],
+            [0.0, 0.0, -far * near / (far - near), 0.0],
+        ]);
+
+        let from_matrix = Frustum3::from_matrix(&matrix);
+        let perspective = frustum();
+        for (a, b) in from_matrix.planes().iter().zip(perspective.planes()) {
+            let (a, b) = (a.normalized(), b.normalized());
+            assert!((a.normal().x - b.normal().x).abs() < 1e-12);
+            assert!((a.normal().y - b.normal().y).abs() < 1e-12);
+            assert!((a.normal().z - b.normal().z).abs() < 1e-12);
+            assert!((a.offset() - b.offset()).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn transformed() {
+        let frustum = frustum().transformed(&Motor3::translation(0.0, 0.0, 10.0));
+        assert!(frustum.contains_point(Point3::at(0.0, 0.0, 15.0)));
+        assert!(!frustum.contains_point(Point3::at(0.0, 0.0, 5.0)));
+    }
+}
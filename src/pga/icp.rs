@@ -0,0 +1,412 @@
+use core::cmp::Ordering;
+use std::vec::Vec;
+
+use crate::{Matrix, Num, Vector, Vector3};
+
+use super::{Motor3, Plane3, Point3};
+
+/// Iterative closest point alignment of point clouds.
+///
+/// Nearest neighbors are found with a k-d tree built once over the target,
+/// so each iteration takes `O(N log M)` for `N` source and `M` target points.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Icp<T> {
+    /// Maximum number of iterations.
+    pub max_iterations: usize,
+
+    /// Iteration stops when the error changes less than this value.
+    pub tolerance: T,
+
+    /// Correspondences further apart than this are ignored.
+    pub max_distance: Option<T>,
+}
+
+/// Result of ICP alignment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct IcpResult<T> {
+    /// Motor that moves source points onto the target.
+    pub motor: Motor3<T>,
+
+    /// Root mean square error of the used correspondences.
+    pub error: T,
+
+    /// Number of performed iterations.
+    pub iterations: usize,
+
+    /// Number of correspondences used in the last iteration.
+    pub matches: usize,
+
+    /// True if the error converged within the tolerance.
+    pub converged: bool,
+}
+
+impl<T> Default for Icp<T>
+where
+    T: Num,
+{
+    fn default() -> Self {
+        Icp::new()
+    }
+}
+
+impl<T> Icp<T>
+where
+    T: Num,
+{
+    /// Creates a new ICP solver with default parameters.
+    pub fn new() -> Self {
+        Icp {
+            max_iterations: 50,
+            tolerance: T::EPSILON.sqrt(),
+            max_distance: None,
+        }
+    }
+
+    /// Aligns source points to target points minimizing point-to-point distances.
+    ///
+    /// Starts from the `initial` estimate.
+    pub fn point_to_point(
+        &self,
+        source: &[Point3<T>],
+        target: &[Point3<T>],
+        initial: Motor3<T>,
+    ) -> IcpResult<T> {
+        let mut a = Vec::with_capacity(source.len());
+        let mut b = Vec::with_capacity(source.len());
+
+        self.run(source, target, initial, |moved, pairs| {
+            a.clear();
+            b.clear();
+            for &(i, j) in pairs {
+                a.push(moved[i]);
+                b.push(target[j]);
+            }
+
+            let step = Motor3::fit(&a, &b, None);
+
+            let mut error = T::ZERO;
+            for (&p, &q) in a.iter().zip(&b) {
+                error += distance2(step.move_point(p), q);
+            }
+            (step, error)
+        })
+    }
+
+    /// Aligns source points to target surface minimizing point-to-plane distances.
+    ///
+    /// Each target point has a tangent plane of the surface at it.
+    /// Starts from the `initial` estimate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target` and `planes` have different lengths.
+    pub fn point_to_plane(
+        &self,
+        source: &[Point3<T>],
+        target: &[Point3<T>],
+        planes: &[Plane3<T>],
+        initial: Motor3<T>,
+    ) -> IcpResult<T> {
+        assert_eq!(
+            target.len(),
+            planes.len(),
+            "Each target point needs a plane"
+        );
+
+        self.run(source, target, initial, |moved, pairs| {
+            // Linearized rotation `w` and translation `t` minimizing
+            // sum of `((p + w x p + t - q) . n)^2`.
+            let mut ata = [[T::ZERO; 6]; 6];
+            let mut atb = [T::ZERO; 6];
+
+            for &(i, j) in pairs {
+                let (px, py, pz) = moved[i].normalized().coords();
+                let (qx, qy, qz) = target[j].normalized().coords();
+                let n = planes[j].normalized();
+                let (nx, ny, nz) = (n.vector().e1, n.vector().e2, n.vector().e3);
+
+                let row = [
+                    py * nz - pz * ny,
+                    pz * nx - px * nz,
+                    px * ny - py * nx,
+                    nx,
+                    ny,
+                    nz,
+                ];
+                let rhs = (qx - px) * nx + (qy - py) * ny + (qz - pz) * nz;
+
+                for (r, ata_r) in ata.iter_mut().enumerate() {
+                    for (c, e) in ata_r.iter_mut().enumerate() {
+                        *e += row[r] * row[c];
+                    }
+                    atb[r] += row[r] * rhs;
+                }
+            }
+
+            let x = Matrix::from_column_arrays(ata)
+                .lu()
+                .solve(&Vector::from_array(atb));
+            let step = match x.map(Vector::into_array) {
+                None => Motor3::IDENTITY,
+                Some([wx, wy, wz, tx, ty, tz]) => {
+                    let angle = (wx * wx + wy * wy + wz * wz).sqrt();
+                    Motor3::translation(tx, ty, tz)
+                        * Motor3::from_axis_angle_about_origin(Vector3::new(wx, wy, wz), angle)
+                }
+            };
+
+            let mut error = T::ZERO;
+            for &(i, j) in pairs {
+                let d = planes[j].signed_distance(step.move_point(moved[i]));
+                error += d * d;
+            }
+            (step, error)
+        })
+    }
+
+    /// Runs ICP loop with the given incremental step solver.
+    ///
+    /// Step receives moved source points and correspondences,
+    /// and returns the incremental motor with the sum of squared errors after it.
+    fn run(
+        &self,
+        source: &[Point3<T>],
+        target: &[Point3<T>],
+        initial: Motor3<T>,
+        mut step: impl FnMut(&[Point3<T>], &[(usize, usize)]) -> (Motor3<T>, T),
+    ) -> IcpResult<T> {
+        let tree = KdTree::new(target);
+        let mut motor = initial;
+        let mut moved = Vec::with_capacity(source.len());
+        let mut pairs = Vec::with_capacity(source.len());
+
+        let mut result = IcpResult {
+            motor,
+            error: T::ZERO,
+            iterations: 0,
+            matches: 0,
+            converged: false,
+        };
+        let mut previous: Option<T> = None;
+
+        for iteration in 1..=self.max_iterations {
+            moved.clear();
+            moved.extend(source.iter().map(|&p| motor.move_point(p)));
+
+            pairs.clear();
+            let mut count = T::ZERO;
+            for (i, &p) in moved.iter().enumerate() {
+                if let Some((j, d2)) = tree.nearest(p) {
+                    if self.max_distance.is_none_or(|max| d2 <= max * max) {
+                        pairs.push((i, j));
+                        count += T::ONE;
+                    }
+                }
+            }
+
+            if pairs.is_empty() {
+                break;
+            }
+
+            let (delta, error) = step(&moved, &pairs);
+            motor = delta * motor;
+            let error = (error / count).sqrt();
+
+            result = IcpResult {
+                motor,
+                error,
+                iterations: iteration,
+                matches: pairs.len(),
+                converged: false,
+            };
+
+            if previous.is_some_and(|previous| (previous - error).abs() <= self.tolerance) {
+                result.converged = true;
+                break;
+            }
+            previous = Some(error);
+        }
+
+        result
+    }
+}
+
+/// Squared distance between two finite points.
+fn distance2<T>(a: Point3<T>, b: Point3<T>) -> T
+where
+    T: Num,
+{
+    let (ax, ay, az) = a.normalized().coords();
+    let (bx, by, bz) = b.normalized().coords();
+    let (dx, dy, dz) = (ax - bx, ay - by, az - bz);
+    dx * dx + dy * dy + dz * dz
+}
+
+/// Balanced k-d tree over points.
+///
+/// Stored implicitly in one array: the median of each range is a node,
+/// points before it form the left subtree and points after it the right one.
+struct KdTree<T> {
+    points: Vec<(usize, [T; 3])>,
+}
+
+impl<T> KdTree<T>
+where
+    T: Num,
+{
+    fn new(points: &[Point3<T>]) -> Self {
+        let mut points: Vec<_> = points
+            .iter()
+            .map(|p| {
+                let (x, y, z) = p.normalized().coords();
+                [x, y, z]
+            })
+            .enumerate()
+            .collect();
+        build(&mut points, 0);
+        KdTree { points }
+    }
+
+    /// Finds the nearest point with squared distance to it.
+    fn nearest(&self, point: Point3<T>) -> Option<(usize, T)> {
+        let (x, y, z) = point.normalized().coords();
+        let mut best = None;
+        search(&self.points, [x, y, z], 0, &mut best);
+        best
+    }
+}
+
+fn build<T>(points: &mut [(usize, [T; 3])], axis: usize)
+where
+    T: Num,
+{
+    if points.len() <= 1 {
+        return;
+    }
+
+    let mid = points.len() / 2;
+    points.select_nth_unstable_by(mid, |a, b| {
+        a.1[axis].partial_cmp(&b.1[axis]).unwrap_or(Ordering::Equal)
+    });
+
+    let (left, right) = points.split_at_mut(mid);
+    build(left, (axis + 1) % 3);
+    build(&mut right[1..], (axis + 1) % 3);
+}
+
+fn search<T>(points: &[(usize, [T; 3])], p: [T; 3], axis: usize, best: &mut Option<(usize, T)>)
+where
+    T: Num,
+{
+    if points.is_empty() {
+        return;
+    }
+
+    let mid = points.len() / 2;
+    let (j, q) = points[mid];
+    let (dx, dy, dz) = (p[0] - q[0], p[1] - q[1], p[2] - q[2]);
+    let d2 = dx * dx + dy * dy + dz * dz;
+    if best.is_none_or(|(_, best)| d2 < best) {
+        *best = Some((j, d2));
+    }
+
+    let d = p[axis] - q[axis];
+    let (near, far) = if d < T::ZERO {
+        (&points[..mid], &points[mid + 1..])
+    } else {
+        (&points[mid + 1..], &points[..mid])
+    };
+    search(near, p, (axis + 1) % 3, best);
+
+    // Points across the splitting plane are at least `d` away.
+    if best.is_none_or(|(_, best)| d * d < best) {
+        search(far, p, (axis + 1) % 3, best);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn motion() -> Motor3<f64> {
+        Motor3::translation(0.1, -0.05, 0.08)
+            * Motor3::from_axis_angle_about_origin(Vector3::new(1.0, 2.0, 2.0), 0.05)
+    }
+
+    #[test]
+    fn kd_tree_matches_brute_force() {
+        // Deterministic pseudo-random coordinates in [-1, 1).
+        let mut seed = 12345u32;
+        let mut random = move || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 8) as f64 / (1u32 << 23) as f64 - 1.0
+        };
+
+        let target: Vec<_> = (0..200)
+            .map(|_| Point3::at(random(), random(), random()))
+            .collect();
+        let tree = KdTree::new(&target);
+        assert!(KdTree::new(&[])
+            .nearest(Point3::at(0.0, 0.0, 0.0))
+            .is_none());
+
+        for _ in 0..100 {
+            let p = Point3::at(random() * 1.5, random() * 1.5, random() * 1.5);
+            let (_, d2) = tree.nearest(p).unwrap();
+            let expected = target
+                .iter()
+                .map(|&q| distance2(p, q))
+                .fold(f64::INFINITY, f64::min);
+            assert_eq!(d2, expected);
+        }
+    }
+
+    #[test]
+    fn point_to_point_aligns_grid() {
+        let mut target = Vec::new();
+        for (x, y, z) in
+            (0..4).flat_map(|x| (0..3).flat_map(move |y| (0..2).map(move |z| (x, y, z))))
+        {
+            target.push(Point3::at(x as f64, 1.5 * y as f64, 0.7 * z as f64));
+        }
+        let inverse = motion().reverse();
+        let source: Vec<_> = target.iter().map(|&p| inverse.move_point(p)).collect();
+
+        let result = Icp::new().point_to_point(&source, &target, Motor3::IDENTITY);
+        assert!(result.converged);
+        assert_eq!(result.matches, target.len());
+        assert!(result.error < 1e-9, "{}", result.error);
+
+        for (&p, &q) in source.iter().zip(&target) {
+            assert!(distance2(result.motor.move_point(p), q) < 1e-18);
+        }
+    }
+
+    #[test]
+    fn point_to_plane_aligns_corner() {
+        let mut target = Vec::new();
+        let mut planes = Vec::new();
+        for (u, v) in (1..5).flat_map(|u| (1..5).map(move |v| (u as f64, v as f64))) {
+            target.extend([
+                Point3::at(0.0, u, v),
+                Point3::at(u, 0.0, v),
+                Point3::at(u, v, 0.0),
+            ]);
+            planes.extend([
+                Plane3::new(0.0, 1.0, 0.0, 0.0),
+                Plane3::new(0.0, 0.0, 1.0, 0.0),
+                Plane3::new(0.0, 0.0, 0.0, 1.0),
+            ]);
+        }
+        let inverse = motion().reverse();
+        let source: Vec<_> = target.iter().map(|&p| inverse.move_point(p)).collect();
+
+        let result = Icp::new().point_to_plane(&source, &target, &planes, Motor3::IDENTITY);
+        assert!(result.converged);
+        assert!(result.error < 1e-9, "{}", result.error);
+
+        for (&p, &q) in source.iter().zip(&target) {
+            assert!(distance2(result.motor.move_point(p), q) < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use crate::{Matrix, Num, Vector};
+
+use super::{
+    batch::{bivector2, bivector2_array, bivector3, bivector3_array, Sandwich},
+    elements::{BiVector3, Pseudo3, Scalar2, Scalar3},
+    Motor2, Motor3,
+};
+
+/// Evaluates polynomial in the matrix with coefficients in ascending order.
+fn polynomial<T, const N: usize>(a: &Matrix<T, N>, coefficients: &[T]) -> Matrix<T, N>
+where
+    T: Num,
+{
+    let mut result = Matrix::<T, N>::from_column_arrays([[T::ZERO; N]; N]);
+    for &c in coefficients.iter().rev() {
+        result *= a;
+        for (i, column) in result.arrays_mut().iter_mut().enumerate() {
+            column[i] += c;
+        }
+    }
+    result
+}
+
+/// Converts small integer to the scalar.
+fn int<T>(n: u32) -> T
+where
+    T: Num,
+{
+    let mut result = T::ZERO;
+    for bit in (0..u32::BITS - n.leading_zeros()).rev() {
+        result *= T::TWO;
+        if n & (1 << bit) != 0 {
+            result += T::ONE;
+        }
+    }
+    result
+}
+
+/// Returns true if the squared angle is too small for the closed forms.
+fn is_small<T>(phi2: T) -> bool
+where
+    T: Num,
+{
+    phi2 < T::EPSILON.sqrt().sqrt()
+}
+
+impl<T> Motor2<T>
+where
+    T: Num,
+{
+    /// Returns the motor `exp(B)` generated by the bivector.
+    ///
+    /// Bivector coefficients are ordered as `[e01, e20, e12]`.
+    pub fn exp(bivector: Vector<T, 3>) -> Self {
+        let b = bivector2(bivector.into_array());
+        let u = b.e12.abs();
+        if u == T::ZERO {
+            return Motor2::new(Scalar2(T::ONE), b);
+        }
+
+        let (sin, cos) = u.sin_cos();
+        Motor2::new(Scalar2(cos), b * (sin / u))
+    }
+
+    /// Returns the bivector `B` such that `exp(B)` is this normalized motor.
+    ///
+    /// Inverse of [`exp`](Self::exp).
+    pub fn log(&self) -> Vector<T, 3> {
+        let (s, b) = (self.scalar(), self.bivector());
+        let sin = b.e12.abs();
+        if sin == T::ZERO {
+            return Vector::from_array(bivector2_array(b));
+        }
+
+        let u = T::atan2(sin, s.0);
+        Vector::from_array(bivector2_array(b * (u / sin)))
+    }
+
+    /// Returns the adjoint matrix of this motor.
+    ///
+    /// Maps bivector coefficients `[e01, e20, e12]` to the same
+    /// bivector moved by this motor.
+    pub fn adjoint(&self) -> Matrix<T, 3> {
+        Sandwich::new(|e| bivector2_array(self.sandwich_point(bivector2(e)))).into_matrix()
+    }
+
+    /// Returns the matrix of the commutator `B * X - X * B`.
+    fn commutator(bivector: Vector<T, 3>) -> (Matrix<T, 3>, T) {
+        let b = bivector2(bivector.into_array());
+        let a = Sandwich::new(|e| {
+            let x = bivector2(e);
+            bivector2_array((b * x).1 - (x * b).1)
+        });
+
+        let phi = T::TWO * b.e12;
+        (a.into_matrix(), phi * phi)
+    }
+
+    /// Returns the left Jacobian of [`exp`](Self::exp) at the bivector.
+    ///
+    /// `exp(B + d)` is approximately `exp(J * d) * exp(B)`.
+    pub fn left_jacobian(bivector: Vector<T, 3>) -> Matrix<T, 3> {
+        let (a, phi2) = Self::commutator(bivector);
+        polynomial(&a, &Self::jacobian_coefficients(phi2))
+    }
+
+    /// Returns the right Jacobian of [`exp`](Self::exp) at the bivector.
+    ///
+    /// `exp(B + d)` is approximately `exp(B) * exp(J * d)`.
+    pub fn right_jacobian(bivector: Vector<T, 3>) -> Matrix<T, 3> {
+        let (a, phi2) = Self::commutator(bivector);
+        let [c0, c1, c2] = Self::jacobian_coefficients(phi2);
+        polynomial(&a, &[c0, -c1, c2])
+    }
+
+    /// Returns the inverse of [`left_jacobian`](Self::left_jacobian).
+    pub fn left_jacobian_inverse(bivector: Vector<T, 3>) -> Matrix<T, 3> {
+        let (a, phi2) = Self::commutator(bivector);
+        polynomial(&a, &Self::jacobian_inverse_coefficients(phi2))
+    }
+
+    /// Returns the inverse of [`right_jacobian`](Self::right_jacobian).
+    pub fn right_jacobian_inverse(bivector: Vector<T, 3>) -> Matrix<T, 3> {
+        let (a, phi2) = Self::commutator(bivector);
+        let [c0, c1, c2] = Self::jacobian_inverse_coefficients(phi2);
+        polynomial(&a, &[c0, -c1, c2])
+    }
+
+    /// Coefficients of `(e^A - 1) / A` where `A^3 = -phi^2 * A`.
+    fn jacobian_coefficients(phi2: T) -> [T; 3] {
+        let c = int::<T>;
+
+        if is_small(phi2) {
+            return [T::ONE, T::HALF - phi2 / c(24), c(6).recip() - phi2 / c(120)];
+        }
+
+        let phi = phi2.sqrt();
+        let (sin, cos) = phi.sin_cos();
+        [T::ONE, (T::ONE - cos) / phi2, (phi - sin) / (phi2 * phi)]
+    }
+
+    /// Coefficients of `A / (e^A - 1)` where `A^3 = -phi^2 * A`.
+    fn jacobian_inverse_coefficients(phi2: T) -> [T; 3] {
+        let c = int::<T>;
+
+        if is_small(phi2) {
+            return [T::ONE, -T::HALF, c(12).recip() + phi2 / c(720)];
+        }
+
+        let half = phi2.sqrt() * T::HALF;
+        let k = half / half.tan();
+        [T::ONE, -T::HALF, (T::ONE - k) / phi2]
+    }
+}
+
+impl<T> Motor3<T>
+where
+    T: Num,
+{
+    /// Returns the motor `exp(B)` generated by the bivector.
+    ///
+    /// Bivector coefficients are ordered as `[e01, e02, e03, e12, e31, e23]`.
+    /// Rotation by angle `a` around normalized line `L` is `exp(-a / 2 * L)`.
+    pub fn exp(bivector: Vector<T, 6>) -> Self {
+        let b = bivector3(bivector.into_array());
+
+        // B = (u + v * e0123) * N where N is a normalized line.
+        let (s, _, p) = b * b;
+        let u2 = -s.0;
+        if u2 <= T::ZERO {
+            return Motor3::new(Scalar3(T::ONE), b, Pseudo3::ZERO);
+        }
+
+        let u = u2.sqrt();
+        let v = -p.e0123 / (T::TWO * u);
+        let (sin, cos) = u.sin_cos();
+        let sinc = sin / u;
+
+        let d = Pseudo3 {
+            e0123: v * (cos - sinc) / u,
+        };
+
+        Motor3::new(Scalar3(cos), b * sinc + b * d, Pseudo3 { e0123: -v * sin })
+    }
+
+    /// Returns the bivector `B` such that `exp(B)` is this normalized motor.
+    ///
+    /// Inverse of [`exp`](Self::exp).
+    pub fn log(&self) -> Vector<T, 6> {
+        let (s, b) = (self.scalar(), self.bivector());
+        let (s2, _, _) = b * b;
+        if s2.0 >= T::ZERO {
+            return Vector::from_array(bivector3_array(b));
+        }
+
+        let sin = (-s2.0).sqrt();
+        let u = T::atan2(sin, s.0);
+        let k = if is_small(u * u) {
+            int::<T>(3).recip() + u * u * T::TWO / int(15)
+        } else {
+            (sin - u * s.0) / (sin * sin * sin)
+        };
+
+        let d = Pseudo3 {
+            e0123: -self.pseudo().e0123 * k,
+        };
+
+        Vector::from_array(bivector3_array(b * (u / sin) + b * d))
+    }
+
+    /// Returns the adjoint matrix of this motor.
+    ///
+    /// Maps bivector coefficients `[e01, e02, e03, e12, e31, e23]` to the same
+    /// bivector moved by this motor, e.g. rates from the body to the world frame.
+    pub fn adjoint(&self) -> Matrix<T, 6> {
+        Sandwich::new(|e| bivector3_array(self.sandwich_line(bivector3(e)))).into_matrix()
+    }
+
+    /// Returns the matrix of the commutator `B * X - X * B`.
+    fn commutator(bivector: Vector<T, 6>) -> (Matrix<T, 6>, T) {
+        let b: BiVector3<T> = bivector3(bivector.into_array());
+        let a = Sandwich::new(|e| {
+            let x = bivector3(e);
+            bivector3_array((b * x).1 - (x * b).1)
+        });
+
+        let (s, _, _) = b * b;
+        (a.into_matrix(), -s.0 * int(4))
+    }
+
+    /// Returns the left Jacobian of [`exp`](Self::exp) at the bivector.
+    ///
+    /// `exp(B + d)` is approximately `exp(J * d) * exp(B)`.
+    pub fn left_jacobian(bivector: Vector<T, 6>) -> Matrix<T, 6> {
+        let (a, phi2) = Self::commutator(bivector);
+        polynomial(&a, &Self::jacobian_coefficients(phi2))
+    }
+
+    /// Returns the right Jacobian of [`exp`](Self::exp) at the bivector.
+    ///
+    /// `exp(B + d)` is approximately `exp(B) * exp(J * d)`.
+    pub fn right_jacobian(bivector: Vector<T, 6>) -> Matrix<T, 6> {
+        let (a, phi2) = Self::commutator(bivector);
+        let [c0, c1, c2, c3, c4] = Self::jacobian_coefficients(phi2);
+        polynomial(&a, &[c0, -c1, c2, -c3, c4])
+    }
+
+    /// Returns the inverse of [`left_jacobian`](Self::left_jacobian).
+    pub fn left_jacobian_inverse(bivector: Vector<T, 6>) -> Matrix<T, 6> {
+        let (a, phi2) = Self::commutator(bivector);
+        polynomial(&a, &Self::jacobian_inverse_coefficients(phi2))
+    }
+
+    /// Returns the inverse of [`right_jacobian`](Self::right_jacobian).
+    pub fn right_jacobian_inverse(bivector: Vector<T, 6>) -> Matrix<T, 6> {
+        let (a, phi2) = Self::commutator(bivector);
+        let [c0, c1, c2, c3, c4] = Self::jacobian_inverse_coefficients(phi2);
+        polynomial(&a, &[c0, -c1, c2, -c3, c4])
+    }
+
+    /// Coefficients of `(e^A - 1) / A` where `A * (A^2 + phi^2)^2 = 0`.
+    fn jacobian_coefficients(phi2: T) -> [T; 5] {
+        let c = int::<T>;
+
+        if is_small(phi2) {
+            let phi4 = phi2 * phi2;
+            return [
+                T::ONE,
+                T::HALF - phi4 / c(720),
+                c(6).recip() - phi4 / c(5040),
+                c(24).recip() - phi2 / c(360),
+                c(120).recip() - phi2 / c(2520),
+            ];
+        }
+
+        let phi = phi2.sqrt();
+        let phi3 = phi2 * phi;
+        let (sin, cos) = phi.sin_cos();
+        [
+            T::ONE,
+            (c(4) - phi * sin - c(4) * cos) / (T::TWO * phi2),
+            (c(4) * phi - c(5) * sin + phi * cos) / (T::TWO * phi3),
+            (T::TWO - phi * sin - T::TWO * cos) / (T::TWO * phi2 * phi2),
+            (T::TWO * phi - c(3) * sin + phi * cos) / (T::TWO * phi3 * phi2),
+        ]
+    }
+
+    /// Coefficients of `A / (e^A - 1)` where `A * (A^2 + phi^2)^2 = 0`.
+    fn jacobian_inverse_coefficients(phi2: T) -> [T; 5] {
+        let c = int::<T>;
+
+        if is_small(phi2) {
+            return [
+                T::ONE,
+                -T::HALF,
+                c(12).recip() - phi2 * phi2 / c(30240),
+                T::ZERO,
+                -c(720).recip() - phi2 / c(15120),
+            ];
+        }
+
+        // Even part is a quadratic in `A^2` matching `(x / 2) * coth(x / 2)`
+        // and its derivative at `x^2 = -phi^2`.
+        let phi = phi2.sqrt();
+        let (sin, cos) = (phi * T::HALF).sin_cos();
+        let cot = cos / sin;
+        let k = phi * T::HALF * cot;
+        let d = (c(8) * sin * sin).recip() - cot / (c(4) * phi);
+        let a4 = (T::ONE - k - d * phi2) / (phi2 * phi2);
+        let a2 = d + T::TWO * a4 * phi2;
+        [T::ONE, -T::HALF, a2, T::ZERO, a4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Point3;
+
+    fn assert_arrays<const N: usize>(a: [f64; N], b: [f64; N], epsilon: f64) {
+        for (x, y) in a.iter().zip(&b) {
+            assert!((x - y).abs() < epsilon, "{a:?} != {b:?}");
+        }
+    }
+
+    fn sample() -> Vector<f64, 6> {
+        Vector::from_array([0.3, -0.2, 0.5, 0.4, -0.7, 0.2])
+    }
+
+    #[test]
+    fn exp_log_round_trip() {
+        let b = sample();
+        assert_arrays(Motor3::exp(b).log().into_array(), b.into_array(), 1e-12);
+
+        let pure = Vector::from_array([1.0, -2.0, 0.5, 0.0, 0.0, 0.0]);
+        assert_arrays(
+            Motor3::exp(pure).log().into_array(),
+            pure.into_array(),
+            1e-12,
+        );
+
+        let b2 = Vector::from_array([0.3, -0.2, 0.9]);
+        assert_arrays(Motor2::exp(b2).log().into_array(), b2.into_array(), 1e-12);
+
+        let m = Motor3::translation(1.0, 2.0, 3.0);
+        let (x, y, z) = Motor3::exp(m.log())
+            .move_point(Point3::at(0.0, 0.0, 0.0))
+            .coords();
+        assert_arrays([x, y, z], [1.0, 2.0, 3.0], 1e-12);
+    }
+
+    #[test]
+    fn jacobians_match_finite_differences() {
+        let b = sample();
+        let m = Motor3::exp(b);
+        let left = *Motor3::left_jacobian(b).arrays();
+        let right = *Motor3::right_jacobian(b).arrays();
+
+        let h = 1e-6;
+        for i in 0..6 {
+            let mut step = b.into_array();
+            step[i] += h;
+            let plus = Motor3::exp(Vector::from_array(step));
+            step[i] -= 2.0 * h;
+            let minus = Motor3::exp(Vector::from_array(step));
+
+            let derivative = |a: Motor3<f64>, b: Motor3<f64>| {
+                let (a, b) = (a.log().into_array(), b.log().into_array());
+                core::array::from_fn(|j| (a[j] - b[j]) / (2.0 * h))
+            };
+            let l = derivative(plus * m.reverse(), minus * m.reverse());
+            let r = derivative(m.reverse() * plus, m.reverse() * minus);
+            assert_arrays(l, left[i], 1e-6);
+            assert_arrays(r, right[i], 1e-6);
+        }
+    }
+
+    #[test]
+    fn jacobian_inverses() {
+        fn assert_identity<const N: usize>(m: Matrix<f64, N>) {
+            for (a, b) in m.arrays().iter().zip(Matrix::<f64, N>::identity().arrays()) {
+                assert_arrays(*a, *b, 1e-12);
+            }
+        }
+
+        let b = sample();
+        assert_identity(Motor3::left_jacobian(b) * Motor3::left_jacobian_inverse(b));
+        assert_identity(Motor3::right_jacobian(b) * Motor3::right_jacobian_inverse(b));
+
+        let b = Vector::from_array([0.3, -0.2, 0.9]);
+        assert_identity(Motor2::left_jacobian(b) * Motor2::left_jacobian_inverse(b));
+        assert_identity(Motor2::right_jacobian(b) * Motor2::right_jacobian_inverse(b));
+    }
+
+    #[test]
+    fn adjoint_moves_bivectors() {
+        let m = Motor3::translation(1.0, -1.0, 2.0) * Motor3::exp(sample());
+        let d = Vector::from_array([0.1, 0.2, -0.3, 0.05, 0.1, -0.2]);
+
+        let moved = (m * Motor3::exp(d) * m.reverse()).log();
+        assert_arrays(moved.into_array(), (m.adjoint() * d).into_array(), 1e-12);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::Num;
+use crate::{Num, Vector3};
 
 use super::{
     elements::{regressive, BiVector3, Vector2},
@@ -124,6 +124,14 @@ where
         let v = (self.vector() | point.bivector()) | !point.bivector();
         Line2::from_vector(v)
     }
+
+    /// Find the bisector of the angle between two lines.
+    ///
+    /// The other bisector is the bisector with the flipped line.
+    /// Returns a line at infinity if lines are parallel with opposite orientation.
+    pub fn bisector(&self, other: Line2<T>) -> Line2<T> {
+        Line2::from_vector(self.0.normalized() + other.0.normalized()).normalized()
+    }
 }
 
 /// Line in 3D is an intersection of two planes.
@@ -149,7 +157,7 @@ where
         T::ZERO,
     ));
 
-    pub(super) const fn bivector(&self) -> BiVector3<T> {
+    pub(crate) const fn bivector(&self) -> BiVector3<T> {
         self.0
     }
 
@@ -162,6 +170,81 @@ where
         Line3(BiVector3::new(e01, e02, e03, e12, e31, e23))
     }
 
+    /// Creates a line through the point in the given direction.
+    pub fn through(point: Point3<T>, direction: Vector3<T>) -> Self {
+        point
+            .normalized()
+            .join(Point3::ideal(direction.x, direction.y, direction.z))
+    }
+
+    /// Creates a line from Plücker coordinates.
+    ///
+    /// Moment is `p x direction` for any point `p` on the line.
+    pub fn from_plucker(direction: Vector3<T>, moment: Vector3<T>) -> Self {
+        Line3(BiVector3::new(
+            moment.x,
+            moment.y,
+            moment.z,
+            direction.z,
+            direction.y,
+            direction.x,
+        ))
+    }
+
+    /// Returns direction of the line.
+    ///
+    /// Line from `a.join(b)` is directed from `a` to `b`.
+    pub fn direction(&self) -> Vector3<T> {
+        Vector3::new(self.0.e23, self.0.e31, self.0.e12)
+    }
+
+    /// Returns moment of the line.
+    pub fn moment(&self) -> Vector3<T> {
+        Vector3::new(self.0.e01, self.0.e02, self.0.e03)
+    }
+
+    /// Returns true if this is a line at infinity.
+    pub fn is_ideal(&self) -> bool {
+        self.0.e12 == T::ZERO && self.0.e31 == T::ZERO && self.0.e23 == T::ZERO
+    }
+
+    /// Returns the point on the line closest to the origin.
+    ///
+    /// Returns ideal point if the line is ideal.
+    pub fn closest_point_to_origin(&self) -> Point3<T> {
+        let d = self.direction().into_array();
+        let m = self.moment().into_array();
+        let [x, y, z] = cross(d, m);
+        Point3::new(dot(d, d), x, y, z).normalized()
+    }
+
+    /// Returns pair of closest points on this line and the other line.
+    ///
+    /// Returns `None` if lines are parallel or either of them is ideal.
+    pub fn closest_points(&self, other: &Self) -> Option<(Point3<T>, Point3<T>)> {
+        let d1 = self.direction().into_array();
+        let d2 = other.direction().into_array();
+        let n = cross(d1, d2);
+        let n2 = dot(n, n);
+        if n2 <= T::EPSILON * dot(d1, d1) * dot(d2, d2) {
+            return None;
+        }
+
+        let (x1, y1, z1) = self.closest_point_to_origin().coords();
+        let (x2, y2, z2) = other.closest_point_to_origin().coords();
+        let p1 = [x1, y1, z1];
+        let p2 = [x2, y2, z2];
+        let delta = [x2 - x1, y2 - y1, z2 - z1];
+
+        let t1 = dot(cross(delta, d2), n) / n2;
+        let t2 = dot(cross(delta, d1), n) / n2;
+
+        let at = |p: [T; 3], d: [T; 3], t: T| {
+            Point3::at(p[0] + d[0] * t, p[1] + d[1] * t, p[2] + d[2] * t)
+        };
+        Some((at(p1, d1, t1), at(p2, d2, t2)))
+    }
+
     /// Returns norm of the line.
     pub fn norm(&self) -> T {
         self.0.norm()
@@ -187,4 +270,99 @@ where
         let r = regressive(self.bivector(), other.trivector());
         Plane3::from_vector(r)
     }
+
+    /// Find the plane through the given point perpendicular to this line.
+    ///
+    /// Plane normal points along the line direction.
+    pub fn perpendicular_plane(&self, point: Point3<T>) -> Plane3<T> {
+        Plane3::from_vector(-(self.0 | point.trivector())).normalized()
+    }
+
+    /// Find the line perpendicular to both lines and intersecting them.
+    ///
+    /// Line is directed along cross product of this and other line directions.
+    /// Returns a line at infinity if lines are parallel.
+    pub fn common_perpendicular(&self, other: Line3<T>) -> Line3<T> {
+        let (_s, bv, _p) = other.0.normalized() * self.0.normalized();
+        Line3(bv).normalized()
+    }
+}
+
+fn dot<T>(a: [T; 3], b: [T; 3]) -> T
+where
+    T: Num,
+{
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross<T>(a: [T; 3], b: [T; 3]) -> [T; 3]
+where
+    T: Num,
+{
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_vector(v: Vector3<f64>, expected: [f64; 3]) {
+        let v = [v.x, v.y, v.z];
+        for (a, b) in v.iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12, "{v:?} != {expected:?}");
+        }
+    }
+
+    fn assert_point(p: Point3<f64>, expected: [f64; 3]) {
+        let (x, y, z) = p.normalized().coords();
+        assert_vector(Vector3::new(x, y, z), expected);
+    }
+
+    #[test]
+    fn plucker_coordinates() {
+        let line = Line3::through(Point3::at(0.0, 1.0, 0.0), Vector3::new(2.0, 0.0, 0.0));
+        assert_vector(line.direction(), [2.0, 0.0, 0.0]);
+        assert_vector(line.moment(), [0.0, 0.0, -2.0]);
+        assert_eq!(line, Line3::from_plucker(line.direction(), line.moment()));
+
+        let joined = Point3::at(1.0, 1.0, 0.0).join(Point3::at(3.0, 1.0, 0.0));
+        assert_vector(joined.direction(), [2.0, 0.0, 0.0]);
+        assert_vector(joined.moment(), [0.0, 0.0, -2.0]);
+
+        assert_point(line.closest_point_to_origin(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn closest_points_of_skew_lines() {
+        let a = Line3::through(Point3::at(5.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let b = Line3::through(Point3::at(0.0, -3.0, 1.0), Vector3::new(0.0, 1.0, 0.0));
+
+        let (p, q) = a.closest_points(&b).unwrap();
+        assert_point(p, [0.0, 0.0, 0.0]);
+        assert_point(q, [0.0, 0.0, 1.0]);
+
+        let c = Line3::through(Point3::at(0.0, 2.0, 0.0), Vector3::new(-3.0, 0.0, 0.0));
+        assert!(a.closest_points(&c).is_none());
+    }
+
+    #[test]
+    fn perpendicular_constructions() {
+        let line = Line3::through(Point3::at(0.0, 0.0, 1.0), Vector3::new(0.0, 2.0, 0.0));
+
+        let plane = line.perpendicular_plane(Point3::at(4.0, 3.0, 0.0));
+        assert_vector(plane.normal(), [0.0, 1.0, 0.0]);
+        assert!((plane.offset() - 3.0).abs() < 1e-12);
+
+        let other = Line3::through(Point3::at(0.0, 0.0, -2.0), Vector3::new(1.0, 0.0, 0.0));
+        let common = line.common_perpendicular(other);
+        assert_vector(common.direction(), [0.0, 0.0, -1.0]);
+        assert_point(common.closest_point_to_origin(), [0.0, 0.0, 0.0]);
+
+        let parallel = Line3::through(Point3::at(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        assert!(line.common_perpendicular(parallel).is_ideal());
+    }
 }
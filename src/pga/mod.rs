@@ -1,20 +1,38 @@
 //! Contains the geometric algebra.
 //!
-//!
-//!
+//! `&` is the regressive product, or join, of points, directions, lines and planes.
+//! It is not implemented for pairs where it is always zero,
+//! such as `Line2 & Line2`, `Plane3 & Line3` and `Plane3 & Plane3`.
 
+mod batch;
+mod direction;
 mod elements;
+mod eskf;
+mod fit;
+mod frustum;
+#[cfg(feature = "std")]
+mod icp;
+mod lie;
 mod line;
 mod motor;
+mod ops;
 mod plane;
 mod point;
 mod reflector;
 mod rotor;
 mod screw;
+mod transform;
 
 pub use self::{
+    direction::{Direction2, Direction3},
+    eskf::Eskf3,
+    frustum::{Containment, Frustum3},
     line::{Line2, Line3},
     motor::{Motor2, Motor3},
     plane::Plane3,
     point::{Point2, Point3},
+    transform::Transform,
 };
+
+#[cfg(feature = "std")]
+pub use self::icp::{Icp, IcpResult};
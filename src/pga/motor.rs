@@ -1,9 +1,9 @@
 use core::ops::Mul;
 
-use crate::Num;
+use crate::{DualQuaternion, Matrix3, Num, Quaternion, Vector3};
 
 use super::{
-    elements::{BiVector2, BiVector3, Pseudo3, Scalar2, Scalar3},
+    elements::{self, BiVector2, BiVector3, Pseudo3, Scalar2, Scalar3, TriVector3, Vector2},
     Line2, Line3, Plane3, Point2, Point3,
 };
 
@@ -37,6 +37,34 @@ where
         self.bivector
     }
 
+    /// Identity motor that leaves everything in place.
+    pub const IDENTITY: Self = Motor2 {
+        scalar: Scalar2(T::ONE),
+        bivector: BiVector2::ZERO,
+    };
+
+    /// Creates a counter-clockwise rotation around the point by the angle in radians.
+    pub fn rotation_about(point: Point2<T>, angle: T) -> Self {
+        let (sin, cos) = (angle * T::HALF).sin_cos();
+
+        Motor2 {
+            scalar: Scalar2(cos),
+            bivector: point.normalized().bivector() * -sin,
+        }
+    }
+
+    /// Creates a translation by the given displacement.
+    pub fn translation(dx: T, dy: T) -> Self {
+        Motor2 {
+            scalar: Scalar2(T::ONE),
+            bivector: BiVector2 {
+                e01: -dx * T::HALF,
+                e20: dy * T::HALF,
+                e12: T::ZERO,
+            },
+        }
+    }
+
     /// Creates a new motor from the given points.
     ///
     /// The resulting motor moves by the double the distance between the two points.
@@ -88,8 +116,13 @@ where
 
     /// Moves the given point by this motor.
     pub fn move_point(&self, point: Point2<T>) -> Point2<T> {
-        let bv0 = self.scalar * point.bivector();
-        let (s, bv1) = self.bivector * point.bivector();
+        Point2::from_bivector(self.sandwich_point(point.bivector()).normalized())
+    }
+
+    /// Applies the sandwich product to the element without normalization.
+    pub(super) fn sandwich_point(&self, point: BiVector2<T>) -> BiVector2<T> {
+        let bv0 = self.scalar * point;
+        let (s, bv1) = self.bivector * point;
         let bv = bv0 + bv1;
 
         let m_s_r = !self.scalar;
@@ -100,13 +133,18 @@ where
         let b = bv * m_s_r;
         let (_zero2, c) = bv * m_bv_r;
 
-        Point2::from_bivector((a + b + c).normalized())
+        a + b + c
     }
 
     /// Moves the given line by this motor.
     pub fn move_line(&self, line: Line2<T>) -> Line2<T> {
-        let v0 = self.scalar * line.vector();
-        let (v1, p) = self.bivector * line.vector();
+        Line2::from_vector(self.sandwich_line(line.vector()).normalized())
+    }
+
+    /// Applies the sandwich product to the element without normalization.
+    pub(super) fn sandwich_line(&self, line: Vector2<T>) -> Vector2<T> {
+        let v0 = self.scalar * line;
+        let (v1, p) = self.bivector * line;
         let v = v0 + v1;
 
         let m_s_r = !self.scalar;
@@ -117,7 +155,44 @@ where
         let _zero2 = p * m_s_r;
         let c = p * m_bv_r;
 
-        Line2::from_vector((a + b + c).normalized())
+        a + b + c
+    }
+
+    /// Returns the reverse of this motor.
+    ///
+    /// For normalized motors this is the inverse motion.
+    pub fn reverse(&self) -> Self {
+        Motor2 {
+            scalar: self.scalar,
+            bivector: !self.bivector,
+        }
+    }
+
+    /// Returns the inverse of this motor.
+    ///
+    /// Unlike [`reverse`](Self::reverse) it does not require the motor to be normalized.
+    /// Returns `None` if the motor is degenerate and has no inverse.
+    pub fn inverse(&self) -> Option<Self> {
+        let norm2 = self.norm2();
+        if norm2 == T::ZERO {
+            return None;
+        }
+
+        Some(Motor2 {
+            scalar: self.scalar / norm2,
+            bivector: !self.bivector / norm2,
+        })
+    }
+
+    /// Returns this motor relative to the other one.
+    ///
+    /// The result `r` satisfies `other * r == self`.
+    /// E.g. pose of a child in the frame of its parent
+    /// or the delta between two snapshots.
+    ///
+    /// Returns `None` if the other motor has no inverse.
+    pub fn relative_to(&self, other: &Self) -> Option<Self> {
+        other.inverse().map(|inverse| inverse * *self)
     }
 
     /// Returns norm of the motor.
@@ -157,6 +232,26 @@ where
         }
     }
 
+    /// Projects the motor back onto valid rigid motions.
+    ///
+    /// In 2D every motor with non-zero norm is a scaled rigid motion,
+    /// so this is the same as [`normalize`](Self::normalize).
+    pub fn renormalize(&mut self) {
+        self.normalize();
+    }
+
+    /// Returns the motor projected onto valid rigid motions.
+    ///
+    /// See [`renormalize`](Self::renormalize).
+    pub fn renormalized(&self) -> Self {
+        self.normalized()
+    }
+
+    /// Returns true if the motor is a valid rigid motion within the tolerance.
+    pub fn is_normalized(&self, epsilon: T) -> bool {
+        (self.norm2() - T::ONE).abs() <= epsilon
+    }
+
     /// Halves the motor.
     pub fn sqrt(&self) -> Self {
         Motor2 {
@@ -254,6 +349,85 @@ where
         self.bivector
     }
 
+    /// Returns the pseudoscalar part of this motor.
+    pub const fn pseudo(&self) -> Pseudo3<T> {
+        self.pseudo
+    }
+
+    /// Identity motor that leaves everything in place.
+    pub const IDENTITY: Self = Motor3 {
+        scalar: Scalar3(T::ONE),
+        bivector: BiVector3::ZERO,
+        pseudo: Pseudo3::ZERO,
+    };
+
+    /// Creates a rotation around the line by the angle in radians.
+    ///
+    /// Rotation follows the right-hand rule around the line direction.
+    /// Line `a.join(b)` is directed from `a` to `b`.
+    pub fn rotation(axis: Line3<T>, angle: T) -> Self {
+        let (sin, cos) = (angle * T::HALF).sin_cos();
+
+        Motor3 {
+            scalar: Scalar3(cos),
+            bivector: axis.normalized().bivector() * -sin,
+            pseudo: Pseudo3::ZERO,
+        }
+    }
+
+    /// Creates a rotation around the axis through the origin by the angle in radians.
+    ///
+    /// Axis does not have to be normalized.
+    pub fn from_axis_angle_about_origin(axis: Vector3<T>, angle: T) -> Self {
+        Quaternion::from_axis_angle(axis, angle).into()
+    }
+
+    /// Creates a translation by the given displacement.
+    pub fn translation(dx: T, dy: T, dz: T) -> Self {
+        Motor3 {
+            scalar: Scalar3(T::ONE),
+            bivector: BiVector3 {
+                e01: -dx * T::HALF,
+                e02: -dy * T::HALF,
+                e03: -dz * T::HALF,
+                e12: T::ZERO,
+                e31: T::ZERO,
+                e23: T::ZERO,
+            },
+            pseudo: Pseudo3::ZERO,
+        }
+    }
+
+    /// Creates a motor that places a camera at `eye` looking at `target`.
+    ///
+    /// Camera looks along Z axis with Y axis up in its local space.
+    /// The motor moves it from the local space to the world.
+    /// `up` must not be parallel to the view direction.
+    pub fn look_at(eye: Point3<T>, target: Point3<T>, up: Vector3<T>) -> Self {
+        let (ex, ey, ez) = eye.normalized().coords();
+        let (tx, ty, tz) = target.normalized().coords();
+
+        let cross = |a: [T; 3], b: [T; 3]| {
+            [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ]
+        };
+        let normalize = |a: [T; 3]| {
+            let norm = (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt();
+            a.map(|v| v / norm)
+        };
+
+        let forward = normalize([tx - ex, ty - ey, tz - ez]);
+        let right = normalize(cross([up.x, up.y, up.z], forward));
+        let up = cross(forward, right);
+
+        let rotation = Quaternion::from_matrix(&Matrix3::from_column_arrays([right, up, forward]));
+
+        Motor3::translation(ex, ey, ez) * Motor3::from(rotation)
+    }
+
     /// Creates a new motor from the given points.
     ///
     /// The resulting motor moves by the double the distance between the two points.
@@ -333,40 +507,143 @@ where
 
     /// Moves the given point by this motor.
     pub fn move_point(&self, point: Point3<T>) -> Point3<T> {
-        let tv0 = self.scalar * point.trivector();
-        let (v, tv1) = self.bivector * point.trivector();
+        Point3::from_trivector(self.sandwich_point(point.trivector()).normalized())
+    }
+
+    /// Applies the sandwich product to the element without normalization.
+    pub(super) fn sandwich_point(&self, point: TriVector3<T>) -> TriVector3<T> {
+        let tv0 = self.scalar * point;
+        let (v0, tv1) = self.bivector * point;
+        let v1 = self.pseudo * point;
+        let v = v0 + v1;
         let tv = tv0 + tv1;
 
         let m_s_r = !self.scalar;
         let m_bv_r = !self.bivector;
+        let m_p_r = !self.pseudo;
 
         let _zero1 = v * m_s_r;
         let (_zero2, a) = v * m_bv_r;
         let b = tv * m_s_r;
         let (_zero3, c) = tv * m_bv_r;
+        let d = v * m_p_r;
+        let _zero4 = tv * m_p_r;
 
-        Point3::from_trivector((a + b + c).normalized())
+        a + b + c + d
     }
 
     /// Moves the given line by this motor.
     pub fn move_line(&self, line: Line3<T>) -> Line3<T> {
-        let bv0 = self.scalar * line.bivector();
-        let (s, bv1, p) = self.bivector * line.bivector();
-        let bv = bv0 + bv1;
+        Line3::from_bivector(self.sandwich_line(line.bivector()).normalized())
+    }
+
+    /// Applies the sandwich product to the element without normalization.
+    pub(super) fn sandwich_line(&self, line: BiVector3<T>) -> BiVector3<T> {
+        let bv0 = self.scalar * line;
+        let (s, bv1, p) = self.bivector * line;
+        let bv2 = self.pseudo * line;
+        let bv = bv0 + bv1 + bv2;
 
         let m_s_r = !self.scalar;
         let m_bv_r = !self.bivector;
+        let m_p_r = !self.pseudo;
 
         let _zero1 = s * m_s_r;
         let a = s * m_bv_r;
 
         let b = bv * m_s_r;
         let (_zero2, c, _zero3) = bv * m_bv_r;
+        let e = bv * m_p_r;
 
         let _zero4 = p * m_s_r;
         let d = p * m_bv_r;
 
-        Line3::from_bivector((a + b + c + d).normalized())
+        a + b + c + d + e
+    }
+
+    /// Moves the given plane by this motor.
+    pub fn move_plane(&self, plane: Plane3<T>) -> Plane3<T> {
+        Plane3::from_vector(self.sandwich_plane(plane.vector()).normalized())
+    }
+
+    /// Applies the sandwich product to the element without normalization.
+    pub(super) fn sandwich_plane(&self, plane: elements::Vector3<T>) -> elements::Vector3<T> {
+        let v0 = self.scalar * plane;
+        let (v1, tv0) = self.bivector * plane;
+        let tv1 = self.pseudo * plane;
+        let v = v0 + v1;
+        let tv = tv0 + tv1;
+
+        let m_s_r = !self.scalar;
+        let m_bv_r = !self.bivector;
+        let m_p_r = !self.pseudo;
+
+        let a = v * m_s_r;
+        let (b, _zero1) = v * m_bv_r;
+        let _zero2 = v * m_p_r;
+        let _zero3 = tv * m_s_r;
+        let (c, _zero4) = tv * m_bv_r;
+        let d = tv * m_p_r;
+
+        a + b + c + d
+    }
+
+    /// Returns the reverse of this motor.
+    ///
+    /// For normalized motors this is the inverse motion.
+    pub fn reverse(&self) -> Self {
+        Motor3 {
+            scalar: self.scalar,
+            bivector: !self.bivector,
+            pseudo: self.pseudo,
+        }
+    }
+
+    /// Returns the inverse of this motor.
+    ///
+    /// Unlike [`reverse`](Self::reverse) it does not require the motor to be normalized.
+    /// Returns `None` if the motor is degenerate and has no inverse.
+    pub fn inverse(&self) -> Option<Self> {
+        let r = self.reverse();
+
+        let (a, b) = self.squared();
+
+        if a == T::ZERO {
+            return None;
+        }
+
+        // Inverse of `a + b * e0123` is `c + d * e0123`.
+        let c = a.recip();
+        let d = Pseudo3 {
+            e0123: -b / (a * a),
+        };
+
+        Some(Motor3 {
+            scalar: r.scalar * c,
+            bivector: r.bivector * c + r.bivector * d,
+            pseudo: r.pseudo * c + r.scalar * d,
+        })
+    }
+
+    /// Returns this motor relative to the other one.
+    ///
+    /// The result `r` satisfies `other * r == self`.
+    /// E.g. pose of a child in the frame of its parent
+    /// or the delta between two snapshots.
+    ///
+    /// Returns `None` if the other motor has no inverse.
+    pub fn relative_to(&self, other: &Self) -> Option<Self> {
+        other.inverse().map(|inverse| inverse * *self)
+    }
+
+    /// Returns motor times its reverse as `a + b * e0123`.
+    ///
+    /// For valid rigid motions `b` is zero, which is the Study condition.
+    fn squared(&self) -> (T, T) {
+        let (s, _zero, p) = self.bivector * !self.bivector;
+        let a = self.scalar.0 * self.scalar.0 + s.0;
+        let b = T::TWO * self.scalar.0 * self.pseudo.e0123 + p.e0123;
+        (a, b)
     }
 
     /// Returns norm of the motor.
@@ -389,6 +666,7 @@ where
             let norm = norm2.sqrt();
             self.scalar /= norm;
             self.bivector /= norm;
+            self.pseudo /= norm;
         }
     }
 
@@ -407,6 +685,43 @@ where
         }
     }
 
+    /// Projects the motor back onto valid rigid motions.
+    ///
+    /// Unlike [`normalize`](Self::normalize) it also enforces the Study condition,
+    /// removing the drift accumulated by long chains of products.
+    pub fn renormalize(&mut self) {
+        *self = self.renormalized();
+    }
+
+    /// Returns the motor projected onto valid rigid motions.
+    ///
+    /// See [`renormalize`](Self::renormalize).
+    pub fn renormalized(&self) -> Self {
+        let (a, b) = self.squared();
+        if a <= T::ZERO {
+            return *self;
+        }
+
+        // Inverse square root of `a + b * e0123` is `c + d * e0123`.
+        let sqrt = a.sqrt();
+        let c = sqrt.recip();
+        let d = Pseudo3 {
+            e0123: -b * T::HALF / (a * sqrt),
+        };
+
+        Motor3 {
+            scalar: self.scalar * c,
+            bivector: self.bivector * c + self.bivector * d,
+            pseudo: self.pseudo * c + self.scalar * d,
+        }
+    }
+
+    /// Returns true if the motor is a valid rigid motion within the tolerance.
+    pub fn is_normalized(&self, epsilon: T) -> bool {
+        let (a, b) = self.squared();
+        (a - T::ONE).abs() <= epsilon && b.abs() <= epsilon
+    }
+
     /// Halves the motor.
     pub fn sqrt(&self) -> Self {
         let a = Motor3 {
@@ -435,13 +750,14 @@ where
 
         let bv1 = self.bivector * rhs.scalar;
         let (s1, bv2, p1) = self.bivector * rhs.bivector;
+        let bv4 = self.bivector * rhs.pseudo;
 
         let p2 = self.pseudo * rhs.scalar;
         let bv3 = self.pseudo * rhs.bivector;
 
         Motor3 {
             scalar: s0 + s1,
-            bivector: bv0 + bv1 + bv2 + bv3,
+            bivector: bv0 + bv1 + bv2 + bv3 + bv4,
             pseudo: p0 + p1 + p2,
         }
         .normalized()
@@ -458,3 +774,290 @@ where
         todo!()
     }
 }
+
+impl<T> From<Quaternion<T>> for Motor3<T>
+where
+    T: Num,
+{
+    /// Creates a rotation around the origin.
+    fn from(q: Quaternion<T>) -> Self {
+        Motor3 {
+            scalar: Scalar3(q.w),
+            bivector: BiVector3 {
+                e01: T::ZERO,
+                e02: T::ZERO,
+                e03: T::ZERO,
+                e12: -q.z,
+                e31: -q.y,
+                e23: -q.x,
+            },
+            pseudo: Pseudo3::ZERO,
+        }
+    }
+}
+
+impl<T> From<Motor3<T>> for Quaternion<T>
+where
+    T: Num,
+{
+    /// Returns the rotation part of the motor.
+    ///
+    /// Translation is discarded.
+    fn from(motor: Motor3<T>) -> Self {
+        Quaternion {
+            x: -motor.bivector.e23,
+            y: -motor.bivector.e31,
+            z: -motor.bivector.e12,
+            w: motor.scalar.0,
+        }
+    }
+}
+
+impl<T> From<DualQuaternion<T>> for Motor3<T>
+where
+    T: Num,
+{
+    fn from(dq: DualQuaternion<T>) -> Self {
+        Motor3 {
+            scalar: Scalar3(dq.real.w),
+            bivector: BiVector3 {
+                e01: -dq.dual.x,
+                e02: -dq.dual.y,
+                e03: -dq.dual.z,
+                e12: -dq.real.z,
+                e31: -dq.real.y,
+                e23: -dq.real.x,
+            },
+            pseudo: Pseudo3 { e0123: -dq.dual.w },
+        }
+    }
+}
+
+impl<T> From<Motor3<T>> for DualQuaternion<T>
+where
+    T: Num,
+{
+    fn from(motor: Motor3<T>) -> Self {
+        DualQuaternion {
+            real: motor.into(),
+            dual: Quaternion {
+                x: -motor.bivector.e01,
+                y: -motor.bivector.e02,
+                z: -motor.bivector.e03,
+                w: -motor.pseudo.e0123,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_point(point: Point3<f64>, expected: (f64, f64, f64)) {
+        let (x, y, z) = point.normalized().coords();
+        let (ex, ey, ez) = expected;
+        assert!(
+            (x - ex).abs() < 1e-12 && (y - ey).abs() < 1e-12 && (z - ez).abs() < 1e-12,
+            "({x}, {y}, {z}) != {expected:?}"
+        );
+    }
+
+    fn assert_motor(a: Motor3<f64>, b: Motor3<f64>) {
+        let p = Point3::at(0.3, -1.2, 2.5);
+        let (x, y, z) = b.move_point(p).normalized().coords();
+        assert_point(a.move_point(p), (x, y, z));
+
+        let q = Point3::at(-2.0, 0.7, 1.1);
+        let (x, y, z) = b.move_point(q).normalized().coords();
+        assert_point(a.move_point(q), (x, y, z));
+    }
+
+    fn z_axis() -> Line3<f64> {
+        Point3::at(0.0, 0.0, 0.0).join(Point3::at(0.0, 0.0, 1.0))
+    }
+
+    fn screw() -> Motor3<f64> {
+        Motor3::translation(1.0, -2.0, 0.5) * Motor3::rotation(z_axis(), 0.7)
+    }
+
+    /// Quarter turn about the z axis combined with a shift of 2 along it.
+    fn quarter_screw() -> Motor3<f64> {
+        let h = core::f64::consts::FRAC_1_SQRT_2;
+        Motor3::new(
+            Scalar3(h),
+            BiVector3 {
+                e01: 0.0,
+                e02: 0.0,
+                e03: -h,
+                e12: -h,
+                e31: 0.0,
+                e23: 0.0,
+            },
+            Pseudo3 { e0123: h },
+        )
+    }
+
+    #[test]
+    fn screw_moves_points_and_lines() {
+        let m = quarter_screw();
+
+        assert_point(m.move_point(Point3::at(0.0, 0.0, 0.0)), (0.0, 0.0, 2.0));
+        assert_point(m.move_point(Point3::at(1.0, 0.0, 0.0)), (0.0, 1.0, 2.0));
+
+        let a = Point3::at(0.0, 0.0, 0.0).join(Point3::at(1.0, 0.0, 0.0));
+        let b = Point3::at(0.0, 0.0, 2.0).join(Point3::at(0.0, 1.0, 2.0));
+        let moved = m.move_line(a).normalized().bivector();
+        let expected = b.normalized().bivector();
+        let error = [
+            moved.e01 - expected.e01,
+            moved.e02 - expected.e02,
+            moved.e03 - expected.e03,
+            moved.e12 - expected.e12,
+            moved.e31 - expected.e31,
+            moved.e23 - expected.e23,
+        ];
+        assert!(
+            error.iter().all(|e| e.abs() < 1e-12),
+            "{moved:?} != {expected:?}"
+        );
+    }
+
+    #[test]
+    fn composition_with_screw() {
+        let h = core::f64::consts::FRAC_1_SQRT_2;
+        let turn = Motor3::new(
+            Scalar3(h),
+            BiVector3 {
+                e01: 0.0,
+                e02: 0.0,
+                e03: 0.0,
+                e12: 0.0,
+                e31: 0.0,
+                e23: h,
+            },
+            Pseudo3 { e0123: 0.0 },
+        );
+        let screw = quarter_screw();
+
+        for (x, y, z) in [(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.5, -2.0, 1.5)] {
+            let p = Point3::at(x, y, z);
+            let expected = turn.move_point(screw.move_point(p)).normalized().coords();
+            assert_point((turn * screw).move_point(p), expected);
+        }
+    }
+
+    #[test]
+    fn normalize_scales_pseudoscalar() {
+        let m = quarter_screw();
+        let scaled = Motor3::new(m.scalar() * 3.0, m.bivector() * 3.0, m.pseudo * 3.0);
+
+        let mut normalized = scaled;
+        normalized.normalize();
+        assert_eq!(normalized, scaled.normalized());
+        assert!((normalized.pseudo.e0123 - m.pseudo.e0123).abs() < 1e-12);
+    }
+
+    #[test]
+    fn identity_keeps_points() {
+        assert_point(
+            Motor3::IDENTITY.move_point(Point3::at(1.0, 2.0, 3.0)),
+            (1.0, 2.0, 3.0),
+        );
+    }
+
+    #[test]
+    fn inverse_of_unnormalized_motor() {
+        let m = screw();
+        let scaled = Motor3::new(m.scalar() * 3.0, m.bivector() * 3.0, m.pseudo() * 3.0);
+
+        let inverse = scaled.inverse().unwrap();
+        assert_motor(scaled * inverse, Motor3::IDENTITY);
+        assert_motor(inverse * m, Motor3::IDENTITY);
+        assert_motor(m * m.reverse(), Motor3::IDENTITY);
+    }
+
+    #[test]
+    fn relative_to_undoes_parent() {
+        let child = screw();
+        let parent = Motor3::rotation(
+            Point3::at(1.0, 0.0, 0.0).join(Point3::at(1.0, 1.0, 0.0)),
+            1.3,
+        ) * Motor3::translation(0.0, 4.0, 0.0);
+
+        let relative = child.relative_to(&parent).unwrap();
+        assert_motor(parent * relative, child);
+        assert_motor(child.relative_to(&child).unwrap(), Motor3::IDENTITY);
+    }
+
+    #[test]
+    fn degenerate_motor_has_no_inverse() {
+        let zero = Motor3::new(Scalar3(0.0), BiVector3::ZERO, Pseudo3 { e0123: 0.0 });
+        assert_eq!(zero.inverse(), None);
+        assert_eq!(screw().relative_to(&zero), None);
+
+        // Ideal line squares to zero.
+        let ideal = Motor3::new(
+            Scalar3(0.0),
+            BiVector3 {
+                e01: 1.0,
+                e02: 2.0,
+                e03: 0.0,
+                e12: 0.0,
+                e31: 0.0,
+                e23: 0.0,
+            },
+            Pseudo3 { e0123: 0.0 },
+        );
+        assert_eq!(ideal.inverse(), None);
+
+        let zero = Motor2::new(Scalar2(0.0), BiVector2::ZERO);
+        assert_eq!(zero.inverse(), None);
+    }
+
+    #[test]
+    fn rotation_follows_right_hand_rule() {
+        let m = Motor3::rotation(z_axis(), core::f64::consts::FRAC_PI_2);
+        assert_point(m.move_point(Point3::at(1.0, 0.0, 0.0)), (0.0, 1.0, 0.0));
+
+        let origin = Motor3::from_axis_angle_about_origin(Vector3::new(0.0, 0.0, 1.0), 0.4);
+        assert_motor(origin, Motor3::rotation(z_axis(), 0.4));
+    }
+
+    #[test]
+    fn translation_moves_origin() {
+        let m = Motor3::translation(1.0, 2.0, 3.0);
+        assert_point(m.move_point(Point3::at(0.0, 0.0, 0.0)), (1.0, 2.0, 3.0));
+    }
+
+    #[test]
+    fn look_at_places_camera() {
+        let m = Motor3::look_at(
+            Point3::at(1.0, 2.0, 3.0),
+            Point3::at(1.0, 2.0, 10.0),
+            Vector3::new(0.0, 1.0, 0.0),
+        );
+        assert_point(m.move_point(Point3::at(0.0, 0.0, 0.0)), (1.0, 2.0, 3.0));
+        assert_point(m.move_point(Point3::at(0.0, 0.0, 1.0)), (1.0, 2.0, 4.0));
+        assert_point(m.move_point(Point3::at(0.0, 1.0, 0.0)), (1.0, 3.0, 3.0));
+    }
+
+    #[test]
+    fn renormalize_removes_drift() {
+        let m = screw();
+        let mut bivector = m.bivector();
+        bivector.e01 += 1e-3;
+        bivector.e12 += 1e-3;
+        let drifted = Motor3::new(m.scalar() * 1.01, bivector, m.pseudo());
+        assert!(!drifted.is_normalized(1e-6));
+
+        let mut fixed = drifted;
+        fixed.renormalize();
+        assert!(fixed.is_normalized(1e-12));
+        assert_eq!(fixed, drifted.renormalized());
+        assert_motor(fixed.renormalized(), fixed);
+
+        assert!(m.is_normalized(1e-12));
+        assert_motor(m.renormalized(), m);
+    }
+}
@@ -0,0 +1,229 @@
+use core::ops::BitAnd;
+
+use crate::Num;
+
+use super::{Direction2, Direction3, Line2, Line3, Plane3, Point2, Point3};
+
+impl<T> BitAnd for Point2<T>
+where
+    T: Num,
+{
+    type Output = Line2<T>;
+
+    /// Regressive product, same as [`Point2::join`].
+    #[inline]
+    fn bitand(self, rhs: Point2<T>) -> Line2<T> {
+        self.join(rhs)
+    }
+}
+
+impl<T> BitAnd for Point3<T>
+where
+    T: Num,
+{
+    type Output = Line3<T>;
+
+    /// Regressive product, same as [`Point3::join`].
+    #[inline]
+    fn bitand(self, rhs: Point3<T>) -> Line3<T> {
+        self.join(rhs)
+    }
+}
+
+impl<T> BitAnd<Point3<T>> for Line3<T>
+where
+    T: Num,
+{
+    type Output = Plane3<T>;
+
+    /// Regressive product, same as [`Line3::join`].
+    #[inline]
+    fn bitand(self, rhs: Point3<T>) -> Plane3<T> {
+        self.join(rhs)
+    }
+}
+
+impl<T> BitAnd<Line3<T>> for Point3<T>
+where
+    T: Num,
+{
+    type Output = Plane3<T>;
+
+    /// Regressive product, same as [`Line3::join`].
+    #[inline]
+    fn bitand(self, rhs: Line3<T>) -> Plane3<T> {
+        rhs.join(self)
+    }
+}
+
+macro_rules! impl_join {
+    ($($lhs:ident.$lget:ident & $rhs:ident.$rget:ident => $out:ident::$from:ident,)*) => {$(
+        impl<T> BitAnd<$rhs<T>> for $lhs<T>
+        where
+            T: Num,
+        {
+            type Output = $out<T>;
+
+            /// Regressive product, also known as join.
+            #[inline]
+            fn bitand(self, rhs: $rhs<T>) -> $out<T> {
+                $out::$from(self.$lget() & rhs.$rget())
+            }
+        }
+    )*};
+}
+
+impl_join! {
+    Point2.bivector & Direction2.bivector => Line2::from_vector,
+    Direction2.bivector & Point2.bivector => Line2::from_vector,
+    Direction2.bivector & Direction2.bivector => Line2::from_vector,
+    Point3.trivector & Direction3.trivector => Line3::from_bivector,
+    Direction3.trivector & Point3.trivector => Line3::from_bivector,
+    Direction3.trivector & Direction3.trivector => Line3::from_bivector,
+    Direction3.trivector & Line3.bivector => Plane3::from_vector,
+    Line3.bivector & Direction3.trivector => Plane3::from_vector,
+}
+
+macro_rules! impl_scalar_join {
+    ($($lhs:ident.$lget:ident & $rhs:ident.$rget:ident,)*) => {$(
+        impl<T> BitAnd<$rhs<T>> for $lhs<T>
+        where
+            T: Num,
+        {
+            type Output = T;
+
+            /// Regressive product, which is a scalar for these grades.
+            #[inline]
+            fn bitand(self, rhs: $rhs<T>) -> T {
+                (self.$lget() & rhs.$rget()).0
+            }
+        }
+    )*};
+}
+
+impl_scalar_join! {
+    Point2.bivector & Line2.vector,
+    Line2.vector & Point2.bivector,
+    Direction2.bivector & Line2.vector,
+    Line2.vector & Direction2.bivector,
+    Point3.trivector & Plane3.vector,
+    Plane3.vector & Point3.trivector,
+    Direction3.trivector & Plane3.vector,
+    Plane3.vector & Direction3.trivector,
+    Line3.bivector & Line3.bivector,
+}
+
+macro_rules! impl_involutions {
+    ($($ty:ident: $get:ident $from:ident,)*) => {$(
+        impl<T> $ty<T>
+        where
+            T: Num,
+        {
+            /// Returns the reverse of the underlying blade.
+            #[inline]
+            pub fn reverse(&self) -> Self {
+                $ty::$from(self.$get().reverse())
+            }
+
+            /// Returns the grade involution of the underlying blade.
+            #[inline]
+            pub fn grade_involution(&self) -> Self {
+                $ty::$from(self.$get().grade_involution())
+            }
+
+            /// Returns the Clifford conjugate of the underlying blade.
+            #[inline]
+            pub fn conjugate(&self) -> Self {
+                $ty::$from(self.$get().conjugate())
+            }
+        }
+    )*};
+}
+
+impl_involutions! {
+    Point2: bivector from_bivector,
+    Line2: vector from_vector,
+    Direction2: bivector from_bivector,
+    Point3: trivector from_trivector,
+    Line3: bivector from_bivector,
+    Plane3: vector from_vector,
+    Direction3: trivector from_trivector,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bitand_is_join() {
+        let (a, b, c) = (
+            Point3::at(1.0, 0.0, 0.0),
+            Point3::at(0.0, 2.0, 0.0),
+            Point3::at(0.0, 0.0, 3.0),
+        );
+
+        assert_eq!(a & b, a.join(b));
+        assert_eq!((a & b) & c, a.join3(b, c));
+        assert_eq!(c & (a & b), a.join3(b, c));
+
+        let (p, q) = (Point2::at(1.0, 2.0), Point2::at(3.0, 5.0));
+        assert_eq!(p & q, p.join(q));
+    }
+
+    #[test]
+    fn bitand_with_directions() {
+        let p = Point3::at(1.0, 2.0, 3.0);
+        let d = Direction3::new(0.0, 1.0, -2.0);
+        assert_eq!(p & d, p & (p + d));
+        assert_eq!(d & p, (p + d) & p);
+
+        let line = p & Point3::at(0.0, 0.0, 1.0);
+        assert_eq!(d & line, (p + d) & line);
+        assert_eq!(line & d, line & (p + d));
+
+        let q = Point2::at(1.0, 2.0);
+        let e = Direction2::new(3.0, -1.0);
+        assert_eq!(q & e, q & (q + e));
+        assert_eq!(e & q, (q + e) & q);
+    }
+
+    #[test]
+    fn scalar_bitand_measures_incidence() {
+        let plane = Plane3::through(
+            Point3::at(0.0, 0.0, 1.0),
+            Point3::at(1.0, 0.0, 1.0),
+            Point3::at(0.0, 1.0, 1.0),
+        )
+        .normalized();
+        let point = Point3::at(4.0, -2.0, 3.0);
+        assert!(((plane & point).abs() - 2.0).abs() < 1e-12);
+        assert_eq!(point & plane, -(plane & point));
+        assert_eq!(plane & Point3::at(5.0, 7.0, 1.0), 0.0);
+
+        let x = Point3::at(0.0, 0.0, 0.0) & Point3::at(1.0, 0.0, 0.0);
+        let y = Point3::at(0.0, 0.0, 0.0) & Point3::at(0.0, 1.0, 0.0);
+        let skew = Point3::at(0.0, 0.0, 2.0) & Point3::at(0.0, 1.0, 2.0);
+        assert_eq!(x & y, 0.0);
+        assert!((x & skew).abs() > 1.0);
+
+        let line = Point2::at(0.0, 0.0) & Point2::at(1.0, 1.0);
+        assert_eq!(line & Point2::at(3.0, 3.0), 0.0);
+        assert_eq!(Point2::at(3.0, 1.0) & line, line & Point2::at(3.0, 1.0));
+        assert_eq!(line & Direction2::new(2.0, 2.0), 0.0);
+    }
+
+    #[test]
+    fn involutions_flip_orientation() {
+        let line = Point3::at(1.0, 2.0, 3.0).join(Point3::at(4.0, 6.0, 3.0));
+        assert_eq!(line.reverse(), line.grade_involution().conjugate());
+        assert_eq!(line.grade_involution(), line);
+
+        let plane = Plane3::new(1.0, 2.0, 3.0, 4.0);
+        assert_eq!(plane.reverse(), plane);
+        assert_eq!(plane.grade_involution(), plane.flip());
+        assert_eq!(plane.conjugate(), plane.flip());
+
+        let point = Point3::at(1.0, 2.0, 3.0);
+        assert_eq!(point.conjugate(), point);
+    }
+}
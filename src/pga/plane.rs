@@ -1,6 +1,6 @@
-use crate::Num;
+use crate::{Num, Vector3};
 
-use super::elements::Vector3;
+use super::{elements, Line3, Point3};
 
 /// Plane is fundamental object in 3d projective geometric algebra.
 /// All other objects are produced by combining planes.
@@ -9,23 +9,23 @@ use super::elements::Vector3;
 #[derive(Clone, Copy, Debug, PartialEq)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Plane3<T>(Vector3<T>);
+pub struct Plane3<T>(elements::Vector3<T>);
 
 impl<T> Plane3<T>
 where
     T: Num,
 {
-    pub(super) const fn vector(&self) -> Vector3<T> {
+    pub(crate) const fn vector(&self) -> elements::Vector3<T> {
         self.0
     }
 
-    pub(super) const fn from_vector(vector: Vector3<T>) -> Self {
+    pub(super) const fn from_vector(vector: elements::Vector3<T>) -> Self {
         Plane3(vector)
     }
 
     /// A vanishing plane.
     /// Also known as the plane at infinity.
-    pub const INFINITY: Self = Plane3(Vector3 {
+    pub const INFINITY: Self = Plane3(elements::Vector3 {
         e0: T::ONE,
         e1: T::ZERO,
         e2: T::ZERO,
@@ -33,7 +33,7 @@ where
     });
 
     /// An XY plane.
-    pub const XY: Self = Plane3(Vector3 {
+    pub const XY: Self = Plane3(elements::Vector3 {
         e0: T::ZERO,
         e1: T::ZERO,
         e2: T::ZERO,
@@ -41,7 +41,7 @@ where
     });
 
     /// A YZ plane.
-    pub const YZ: Self = Plane3(Vector3 {
+    pub const YZ: Self = Plane3(elements::Vector3 {
         e0: T::ZERO,
         e1: T::ONE,
         e2: T::ZERO,
@@ -49,7 +49,7 @@ where
     });
 
     /// A XZ plane.
-    pub const XZ: Self = Plane3(Vector3 {
+    pub const XZ: Self = Plane3(elements::Vector3 {
         e0: T::ZERO,
         e1: T::ZERO,
         e2: T::ONE,
@@ -58,7 +58,67 @@ where
 
     /// Creates a new plane from projective vector elements.
     pub const fn new(e0: T, e1: T, e2: T, e3: T) -> Self {
-        Plane3(Vector3 { e0, e1, e2, e3 })
+        Plane3(elements::Vector3 { e0, e1, e2, e3 })
+    }
+
+    /// Returns signed distance from the plane to the finite point.
+    ///
+    /// Distance is positive on the side the normal points to.
+    pub fn signed_distance(&self, point: Point3<T>) -> T {
+        let v = self.0.normalized();
+        let (x, y, z) = point.normalized().coords();
+        v.e0 + v.e1 * x + v.e2 * y + v.e3 * z
+    }
+
+    /// Creates a plane through the point with the given normal.
+    pub fn from_point_normal(point: Point3<T>, normal: Vector3<T>) -> Self {
+        let (x, y, z) = point.normalized().coords();
+        let e0 = -(normal.x * x + normal.y * y + normal.z * z);
+        Plane3::new(e0, normal.x, normal.y, normal.z).normalized()
+    }
+
+    /// Creates a plane with the given normal at signed distance from the origin.
+    ///
+    /// Points `p` on the plane satisfy `dot(normal, p) == distance` for unit normal.
+    pub fn from_normal_distance(normal: Vector3<T>, distance: T) -> Self {
+        let plane = Plane3::new(T::ZERO, normal.x, normal.y, normal.z).normalized();
+        Plane3::new(-distance, plane.0.e1, plane.0.e2, plane.0.e3)
+    }
+
+    /// Creates a plane through three points.
+    ///
+    /// Same as [`Point3::join3`].
+    /// Points appear counter-clockwise when viewed from the negative side.
+    pub fn through(p: Point3<T>, q: Point3<T>, r: Point3<T>) -> Self {
+        p.join3(q, r)
+    }
+
+    /// Returns unit normal of the plane.
+    pub fn normal(&self) -> Vector3<T> {
+        let v = self.0.normalized();
+        Vector3::new(v.e1, v.e2, v.e3)
+    }
+
+    /// Returns signed distance from the origin to the plane along its normal.
+    pub fn offset(&self) -> T {
+        -self.0.normalized().e0
+    }
+
+    /// Returns the same plane with opposite orientation.
+    pub fn flip(&self) -> Self {
+        Plane3(-self.0)
+    }
+
+    /// Find the line through the given point perpendicular to this plane.
+    ///
+    /// Line is directed along the plane normal.
+    pub fn perpendicular_line(&self, point: Point3<T>) -> Line3<T> {
+        Line3::from_bivector(point.trivector() | self.0).normalized()
+    }
+
+    /// Returns true if this is a plane at infinity.
+    pub fn is_ideal(&self) -> bool {
+        self.0.e1 == T::ZERO && self.0.e2 == T::ZERO && self.0.e3 == T::ZERO
     }
 
     /// Returns norm of the line.
@@ -81,3 +141,63 @@ where
         Plane3(self.0.normalized())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-12, "{a} != {b}");
+    }
+
+    fn assert_normal(plane: Plane3<f64>, expected: [f64; 3]) {
+        let n = plane.normal();
+        for (a, b) in [n.x, n.y, n.z].iter().zip(&expected) {
+            assert_close(*a, *b);
+        }
+    }
+
+    #[test]
+    fn constructors_agree() {
+        let a = Plane3::from_point_normal(Point3::at(0.0, 0.0, 2.0), Vector3::new(0.0, 0.0, 3.0));
+        let b = Plane3::from_normal_distance(Vector3::new(0.0, 0.0, 3.0), 2.0);
+        let c = Plane3::through(
+            Point3::at(0.0, 0.0, 2.0),
+            Point3::at(0.0, 1.0, 2.0),
+            Point3::at(1.0, 0.0, 2.0),
+        );
+
+        for plane in [a, b, c] {
+            assert_normal(plane, [0.0, 0.0, 1.0]);
+            assert_close(plane.offset(), 2.0);
+        }
+    }
+
+    #[test]
+    fn signed_distance_follows_normal() {
+        let plane = Plane3::from_normal_distance(Vector3::new(3.0, 4.0, 0.0), -1.0);
+        assert_close(plane.signed_distance(Point3::at(0.0, 0.0, 7.0)), 1.0);
+        assert_close(plane.signed_distance(Point3::at(3.0, 4.0, 0.0)), 6.0);
+        assert_close(
+            plane.flip().signed_distance(Point3::at(3.0, 4.0, 0.0)),
+            -6.0,
+        );
+        assert_close(plane.flip().offset(), 1.0);
+    }
+
+    #[test]
+    fn perpendicular_line_through_point() {
+        let plane = Plane3::from_normal_distance(Vector3::new(0.0, 0.0, 2.0), 1.0);
+        let line = plane.perpendicular_line(Point3::at(3.0, 4.0, 5.0));
+
+        let d = line.direction();
+        assert_close(d.x, 0.0);
+        assert_close(d.y, 0.0);
+        assert_close(d.z, 1.0);
+
+        let (x, y, z) = line.closest_point_to_origin().coords();
+        assert_close(x, 3.0);
+        assert_close(y, 4.0);
+        assert_close(z, 0.0);
+    }
+}
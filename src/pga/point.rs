@@ -85,6 +85,65 @@ where
         (self.0.e20, self.0.e01)
     }
 
+    /// Returns the element scaled to unit weight.
+    ///
+    /// Unlike normalization keeps the sign of coordinates for negative weight.
+    /// Ideal points are returned as is.
+    pub(super) fn unitized(&self) -> BiVector2<T> {
+        if self.is_ideal() {
+            self.0
+        } else {
+            self.0 * self.0.e12.recip()
+        }
+    }
+
+    /// Returns the average of finite points.
+    ///
+    /// Ideal points are ignored.
+    /// Returns `None` if there are no finite points.
+    pub fn centroid(points: &[Point2<T>]) -> Option<Point2<T>> {
+        let mut sum = BiVector2::ZERO;
+        let mut count = T::ZERO;
+        for point in points {
+            if !point.is_ideal() {
+                sum = sum + point.unitized();
+                count += T::ONE;
+            }
+        }
+
+        if count == T::ZERO {
+            None
+        } else {
+            Some(Point2(sum * count.recip()))
+        }
+    }
+
+    /// Returns weighted combination of points.
+    ///
+    /// Finite points are averaged with the weights,
+    /// while weighted ideal points offset the result as directions.
+    /// If weights of finite points sum to zero the result is ideal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn barycentric(points: &[Point2<T>], weights: &[T]) -> Point2<T> {
+        assert_eq!(points.len(), weights.len(), "Weights must match points");
+
+        let mut sum = BiVector2::ZERO;
+        for (point, &weight) in points.iter().zip(weights) {
+            sum = sum + point.unitized() * weight;
+        }
+        Point2(Point2(sum).unitized())
+    }
+
+    /// Linearly interpolates between two points.
+    ///
+    /// If one of the points is ideal, moves along its direction.
+    pub fn lerp(&self, other: Point2<T>, t: T) -> Point2<T> {
+        *self + (other - *self) * t
+    }
+
     /// Make this line act as a reflector.
     ///
     /// Reflects a point.
@@ -114,6 +173,14 @@ where
         Line2::from_vector(regressive(self.bivector(), other.bivector()))
     }
 
+    /// Find the perpendicular bisector of the segment between two points.
+    ///
+    /// The other point is on the positive side of the line.
+    pub fn perpendicular_bisector(&self, other: Point2<T>) -> Line2<T> {
+        let mid = self.0.normalized() + other.0.normalized();
+        Line2::from_vector(mid | self.join(other).vector()).normalized()
+    }
+
     /// Find orthogonal projection of this point to the line.
     pub fn project_to(&self, line: Line2<T>) -> Point2<T> {
         let (_zero, bv) = !line.vector() * (self.bivector() | line.vector());
@@ -132,7 +199,7 @@ impl<T> Point3<T>
 where
     T: Num,
 {
-    pub(super) const fn trivector(&self) -> TriVector3<T> {
+    pub(crate) const fn trivector(&self) -> TriVector3<T> {
         self.0
     }
 
@@ -209,6 +276,65 @@ where
         (self.0.e032, self.0.e013, self.0.e021)
     }
 
+    /// Returns the element scaled to unit weight.
+    ///
+    /// Unlike normalization keeps the sign of coordinates for negative weight.
+    /// Ideal points are returned as is.
+    pub(super) fn unitized(&self) -> TriVector3<T> {
+        if self.is_ideal() {
+            self.0
+        } else {
+            self.0 * self.0.e123.recip()
+        }
+    }
+
+    /// Returns the average of finite points.
+    ///
+    /// Ideal points are ignored.
+    /// Returns `None` if there are no finite points.
+    pub fn centroid(points: &[Point3<T>]) -> Option<Point3<T>> {
+        let mut sum = TriVector3::ZERO;
+        let mut count = T::ZERO;
+        for point in points {
+            if !point.is_ideal() {
+                sum = sum + point.unitized();
+                count += T::ONE;
+            }
+        }
+
+        if count == T::ZERO {
+            None
+        } else {
+            Some(Point3(sum * count.recip()))
+        }
+    }
+
+    /// Returns weighted combination of points.
+    ///
+    /// Finite points are averaged with the weights,
+    /// while weighted ideal points offset the result as directions.
+    /// If weights of finite points sum to zero the result is ideal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if slices have different lengths.
+    pub fn barycentric(points: &[Point3<T>], weights: &[T]) -> Point3<T> {
+        assert_eq!(points.len(), weights.len(), "Weights must match points");
+
+        let mut sum = TriVector3::ZERO;
+        for (point, &weight) in points.iter().zip(weights) {
+            sum = sum + point.unitized() * weight;
+        }
+        Point3(Point3(sum).unitized())
+    }
+
+    /// Linearly interpolates between two points.
+    ///
+    /// If one of the points is ideal, moves along its direction.
+    pub fn lerp(&self, other: Point3<T>, t: T) -> Point3<T> {
+        *self + (other - *self) * t
+    }
+
     /// Make this line act as a reflector.
     ///
     /// Reflects a point.
@@ -253,3 +379,55 @@ where
         Point3::from_trivector(tv)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn perpendicular_bisector_between_points() {
+        let (a, b) = (Point2::at(1.0, 1.0), Point2::at(3.0, 1.0));
+        let line = a.perpendicular_bisector(b);
+
+        let (a, b, c) = line.abc();
+        assert!((a - 1.0).abs() < 1e-12 && b.abs() < 1e-12 && (c + 2.0).abs() < 1e-12);
+    }
+
+    fn assert_point(p: Point3<f64>, expected: [f64; 3]) {
+        let (x, y, z) = p.normalized().coords();
+        for (a, b) in [x, y, z].iter().zip(&expected) {
+            assert!((a - b).abs() < 1e-12, "{:?} != {expected:?}", (x, y, z));
+        }
+    }
+
+    #[test]
+    fn affine_combinations() {
+        let points = [
+            Point3::at(0.0, 0.0, 0.0),
+            Point3::new(2.0, 4.0, 0.0, 0.0),
+            Point3::at(0.0, 3.0, 0.0),
+            Point3::ideal(1.0, 0.0, 0.0),
+        ];
+        assert_point(Point3::centroid(&points).unwrap(), [2.0 / 3.0, 1.0, 0.0]);
+        assert!(Point3::centroid(&points[3..]).is_none());
+
+        let p = Point3::barycentric(&points, &[0.5, 0.25, 0.25, 2.0]);
+        assert_point(p, [2.5, 0.75, 0.0]);
+
+        let a = Point3::at(1.0, 1.0, 1.0);
+        assert_point(a.lerp(Point3::at(3.0, 5.0, 1.0), 0.25), [1.5, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn point_direction_arithmetic() {
+        let (a, b) = (Point3::at(1.0, 2.0, 3.0), Point3::at(4.0, 6.0, 3.0));
+        let d = b - a;
+        assert_eq!(d.coords(), (3.0, 4.0, 0.0));
+        assert_eq!(d.norm(), 5.0);
+        assert_point(a + d, [4.0, 6.0, 3.0]);
+        assert_point(b - d * 2.0, [-2.0, -2.0, 3.0]);
+
+        let ideal = Point3::ideal(0.0, 0.0, 1.0);
+        assert_eq!((ideal - a).coords(), (0.0, 0.0, 1.0));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::Num;
+
+use super::{
+    Direction2, Direction3, Frustum3, Line2, Line3, Motor2, Motor3, Plane3, Point2, Point3,
+};
+
+/// Sandwich transformation of a primitive.
+///
+/// Implemented by motors for every primitive of the same dimension,
+/// including ideal elements and other motors,
+/// so generic code can move any object.
+pub trait Transform<P> {
+    /// Returns the primitive moved by this transformation.
+    fn apply(&self, primitive: P) -> P;
+}
+
+impl<T> Transform<Point2<T>> for Motor2<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, point: Point2<T>) -> Point2<T> {
+        self.move_point(point)
+    }
+}
+
+impl<T> Transform<Line2<T>> for Motor2<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, line: Line2<T>) -> Line2<T> {
+        self.move_line(line)
+    }
+}
+
+impl<T> Transform<Direction2<T>> for Motor2<T>
+where
+    T: Num,
+{
+    /// Rotates the direction, translation does not affect it.
+    #[inline]
+    fn apply(&self, direction: Direction2<T>) -> Direction2<T> {
+        Direction2::from_bivector(self.sandwich_point(direction.bivector()))
+    }
+}
+
+impl<T> Transform<Motor2<T>> for Motor2<T>
+where
+    T: Num,
+{
+    /// Conjugates the motor, expressing it in the moved frame.
+    #[inline]
+    fn apply(&self, motor: Motor2<T>) -> Motor2<T> {
+        *self * motor * self.reverse()
+    }
+}
+
+impl<T> Transform<Point3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, point: Point3<T>) -> Point3<T> {
+        self.move_point(point)
+    }
+}
+
+impl<T> Transform<Direction3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    /// Rotates the direction, translation does not affect it.
+    #[inline]
+    fn apply(&self, direction: Direction3<T>) -> Direction3<T> {
+        Direction3::from_trivector(self.sandwich_point(direction.trivector()))
+    }
+}
+
+impl<T> Transform<Line3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, line: Line3<T>) -> Line3<T> {
+        self.move_line(line)
+    }
+}
+
+impl<T> Transform<Plane3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, plane: Plane3<T>) -> Plane3<T> {
+        self.move_plane(plane)
+    }
+}
+
+impl<T> Transform<Motor3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    /// Conjugates the motor, expressing it in the moved frame.
+    #[inline]
+    fn apply(&self, motor: Motor3<T>) -> Motor3<T> {
+        *self * motor * self.reverse()
+    }
+}
+
+impl<T> Transform<Frustum3<T>> for Motor3<T>
+where
+    T: Num,
+{
+    #[inline]
+    fn apply(&self, frustum: Frustum3<T>) -> Frustum3<T> {
+        frustum.transformed(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: (f64, f64, f64), b: (f64, f64, f64)) {
+        assert!(
+            (a.0 - b.0).abs() < 1e-12 && (a.1 - b.1).abs() < 1e-12 && (a.2 - b.2).abs() < 1e-12,
+            "{a:?} != {b:?}"
+        );
+    }
+
+    #[test]
+    fn translation_keeps_direction() {
+        let motor = Motor3::translation(1.0, 2.0, 3.0);
+        let direction = motor.apply(Direction3::new(1.0, 0.0, 0.0));
+        assert_close(direction.coords(), (1.0, 0.0, 0.0));
+
+        let motor = Motor2::translation(1.0, 2.0);
+        let (x, y) = motor.apply(Direction2::new(0.0, 1.0)).coords();
+        assert_close((x, y, 0.0), (0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn rotation_turns_direction() {
+        let axis = Point3::at(0.0, 0.0, 0.0).join(Point3::at(0.0, 0.0, 1.0));
+        let motor = Motor3::rotation(axis, core::f64::consts::FRAC_PI_2);
+        let direction = motor.apply(Direction3::new(1.0, 0.0, 0.0));
+        assert_close(direction.coords(), (0.0, 1.0, 0.0));
+
+        let motor = Motor2::rotation_about(Point2::at(5.0, 5.0), core::f64::consts::FRAC_PI_2);
+        let (x, y) = motor.apply(Direction2::new(1.0, 0.0)).coords();
+        assert_close((x, y, 0.0), (0.0, 1.0, 0.0));
+    }
+
+    #[test]
+    fn conjugated_motor_acts_in_moved_frame() {
+        let frame = Motor3::translation(1.0, 2.0, 3.0);
+        let axis = Point3::at(0.0, 0.0, 0.0).join(Point3::at(0.0, 0.0, 1.0));
+        let local = Motor3::rotation(axis, core::f64::consts::FRAC_PI_2);
+
+        // Rotation around the Z axis moved to (1, 2, 3).
+        let moved = frame.apply(local);
+        let point = moved.apply(Point3::at(2.0, 2.0, 0.0)).normalized();
+        assert_close(point.coords(), (1.0, 3.0, 0.0));
+
+        let plane = moved.apply(Plane3::new(-2.0, 1.0, 0.0, 0.0));
+        let n = plane.normal();
+        assert_close((n.x, n.y, n.z), (0.0, 1.0, 0.0));
+        assert!((plane.offset() - 3.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,493 @@
+//! Quaternions and dual quaternions.
+//!
+//! Provided for interoperability with file formats and engines
+//! that represent rotations and rigid motions this way.
+//! Both convert exactly to and from [`Motor3`](crate::Motor3).
+
+use core::ops::{Add, Mul, Neg, Sub};
+
+use crate::{Matrix3, Num, Vector3};
+
+/// Quaternion in Hamilton convention.
+///
+/// Unit quaternions represent rotations in 3D space.
+/// Components are stored in `x, y, z, w` order as in glTF.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion<T> {
+    /// Coefficient of `i`.
+    pub x: T,
+
+    /// Coefficient of `j`.
+    pub y: T,
+
+    /// Coefficient of `k`.
+    pub z: T,
+
+    /// Scalar part.
+    pub w: T,
+}
+
+impl<T> Quaternion<T> {
+    /// Creates a new quaternion from its components.
+    pub const fn new(x: T, y: T, z: T, w: T) -> Self {
+        Quaternion { x, y, z, w }
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Num,
+{
+    /// Identity rotation.
+    pub const IDENTITY: Self = Quaternion {
+        x: T::ZERO,
+        y: T::ZERO,
+        z: T::ZERO,
+        w: T::ONE,
+    };
+
+    /// Creates a rotation around the axis by the angle in radians.
+    ///
+    /// Axis does not have to be normalized.
+    pub fn from_axis_angle(axis: Vector3<T>, angle: T) -> Self {
+        let norm = (axis.x * axis.x + axis.y * axis.y + axis.z * axis.z).sqrt();
+        if norm == T::ZERO {
+            return Quaternion::IDENTITY;
+        }
+
+        let (sin, cos) = (angle * T::HALF).sin_cos();
+        let s = sin / norm;
+
+        Quaternion {
+            x: axis.x * s,
+            y: axis.y * s,
+            z: axis.z * s,
+            w: cos,
+        }
+    }
+
+    /// Returns normalized rotation axis and angle in radians.
+    ///
+    /// Returns X axis with zero angle for identity rotation.
+    pub fn to_axis_angle(&self) -> (Vector3<T>, T) {
+        let q = self.normalized();
+        let norm = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+
+        if norm == T::ZERO {
+            return (Vector3::new(T::ONE, T::ZERO, T::ZERO), T::ZERO);
+        }
+
+        let angle = T::TWO * norm.atan2(q.w);
+        let axis = Vector3::new(q.x / norm, q.y / norm, q.z / norm);
+        (axis, angle)
+    }
+
+    /// Creates a quaternion from the rotation matrix.
+    ///
+    /// The matrix must be orthonormal with determinant 1.
+    pub fn from_matrix(matrix: &Matrix3<T>) -> Self {
+        let e = matrix.arrays();
+        let m = |row: usize, col: usize| e[col][row];
+
+        let trace = m(0, 0) + m(1, 1) + m(2, 2);
+
+        if trace > T::ZERO {
+            let s = (trace + T::ONE).sqrt() * T::TWO;
+            Quaternion {
+                x: (m(2, 1) - m(1, 2)) / s,
+                y: (m(0, 2) - m(2, 0)) / s,
+                z: (m(1, 0) - m(0, 1)) / s,
+                w: s / (T::TWO + T::TWO),
+            }
+        } else if m(0, 0) > m(1, 1) && m(0, 0) > m(2, 2) {
+            let s = (T::ONE + m(0, 0) - m(1, 1) - m(2, 2)).sqrt() * T::TWO;
+            Quaternion {
+                x: s / (T::TWO + T::TWO),
+                y: (m(0, 1) + m(1, 0)) / s,
+                z: (m(0, 2) + m(2, 0)) / s,
+                w: (m(2, 1) - m(1, 2)) / s,
+            }
+        } else if m(1, 1) > m(2, 2) {
+            let s = (T::ONE + m(1, 1) - m(0, 0) - m(2, 2)).sqrt() * T::TWO;
+            Quaternion {
+                x: (m(0, 1) + m(1, 0)) / s,
+                y: s / (T::TWO + T::TWO),
+                z: (m(1, 2) + m(2, 1)) / s,
+                w: (m(0, 2) - m(2, 0)) / s,
+            }
+        } else {
+            let s = (T::ONE + m(2, 2) - m(0, 0) - m(1, 1)).sqrt() * T::TWO;
+            Quaternion {
+                x: (m(0, 2) + m(2, 0)) / s,
+                y: (m(1, 2) + m(2, 1)) / s,
+                z: s / (T::TWO + T::TWO),
+                w: (m(1, 0) - m(0, 1)) / s,
+            }
+        }
+    }
+
+    /// Returns rotation matrix of this quaternion.
+    ///
+    /// The quaternion must be normalized.
+    pub fn to_matrix(&self) -> Matrix3<T> {
+        let Quaternion { x, y, z, w } = *self;
+
+        let (xx, yy, zz) = (x * x, y * y, z * z);
+        let (xy, xz, yz) = (x * y, x * z, y * z);
+        let (wx, wy, wz) = (w * x, w * y, w * z);
+
+        let one = T::ONE;
+        let two = T::TWO;
+
+        Matrix3::from_row_arrays([
+            [one - two * (yy + zz), two * (xy - wz), two * (xz + wy)],
+            [two * (xy + wz), one - two * (xx + zz), two * (yz - wx)],
+            [two * (xz - wy), two * (yz + wx), one - two * (xx + yy)],
+        ])
+    }
+
+    /// Returns dot product of two quaternions.
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y + self.z * other.z + self.w * other.w
+    }
+
+    /// Returns norm of the quaternion.
+    pub fn norm(&self) -> T {
+        self.norm2().sqrt()
+    }
+
+    /// Returns squared norm of the quaternion.
+    pub fn norm2(&self) -> T {
+        self.dot(self)
+    }
+
+    /// Normalizes the quaternion.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns a normalized quaternion.
+    pub fn normalized(&self) -> Self {
+        let norm2 = self.norm2();
+        if norm2 != T::ZERO {
+            *self * norm2.sqrt().recip()
+        } else {
+            *self
+        }
+    }
+
+    /// Returns conjugate of the quaternion.
+    ///
+    /// For unit quaternions this is the inverse rotation.
+    pub fn conjugate(&self) -> Self {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Returns multiplicative inverse of the quaternion.
+    pub fn inverse(&self) -> Self {
+        self.conjugate() * self.norm2().recip()
+    }
+
+    /// Rotates the vector by this quaternion.
+    ///
+    /// The quaternion must be normalized.
+    pub fn rotate(&self, vector: Vector3<T>) -> Vector3<T> {
+        let v = Quaternion::new(vector.x, vector.y, vector.z, T::ZERO);
+        let r = *self * v * self.conjugate();
+        Vector3::new(r.x, r.y, r.z)
+    }
+
+    /// Spherical linear interpolation between two rotations.
+    ///
+    /// Interpolates along the shortest path.
+    /// Both quaternions must be normalized.
+    pub fn slerp(&self, other: &Self, t: T) -> Self {
+        let mut cos = self.dot(other);
+        let mut other = *other;
+
+        if cos < T::ZERO {
+            cos = -cos;
+            other = -other;
+        }
+
+        // Fall back to linear interpolation for nearly identical rotations.
+        if cos > T::ONE - T::EPSILON.sqrt() {
+            return (*self * (T::ONE - t) + other * t).normalized();
+        }
+
+        let angle = cos.acos();
+        let sin = angle.sin();
+        let a = ((T::ONE - t) * angle).sin() / sin;
+        let b = (t * angle).sin() / sin;
+
+        *self * a + other * b
+    }
+}
+
+impl<T> Mul for Quaternion<T>
+where
+    T: Num,
+{
+    type Output = Quaternion<T>;
+
+    /// Hamilton product.
+    fn mul(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion {
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+        }
+    }
+}
+
+impl<T> Mul<T> for Quaternion<T>
+where
+    T: Num,
+{
+    type Output = Quaternion<T>;
+
+    fn mul(self, rhs: T) -> Quaternion<T> {
+        Quaternion {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+            w: self.w * rhs,
+        }
+    }
+}
+
+impl<T> Add for Quaternion<T>
+where
+    T: Num,
+{
+    type Output = Quaternion<T>;
+
+    fn add(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+            w: self.w + rhs.w,
+        }
+    }
+}
+
+impl<T> Sub for Quaternion<T>
+where
+    T: Num,
+{
+    type Output = Quaternion<T>;
+
+    fn sub(self, rhs: Quaternion<T>) -> Quaternion<T> {
+        Quaternion {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+            w: self.w - rhs.w,
+        }
+    }
+}
+
+impl<T> Neg for Quaternion<T>
+where
+    T: Num,
+{
+    type Output = Quaternion<T>;
+
+    fn neg(self) -> Quaternion<T> {
+        Quaternion {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: -self.w,
+        }
+    }
+}
+
+/// Dual quaternion `real + ε dual`.
+///
+/// Unit dual quaternions represent rigid motions in 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DualQuaternion<T> {
+    /// Real part, the rotation.
+    pub real: Quaternion<T>,
+
+    /// Dual part, encodes translation.
+    pub dual: Quaternion<T>,
+}
+
+impl<T> DualQuaternion<T> {
+    /// Creates a new dual quaternion from real and dual parts.
+    pub const fn new(real: Quaternion<T>, dual: Quaternion<T>) -> Self {
+        DualQuaternion { real, dual }
+    }
+}
+
+impl<T> DualQuaternion<T>
+where
+    T: Num,
+{
+    /// Identity motion.
+    pub const IDENTITY: Self = DualQuaternion {
+        real: Quaternion::IDENTITY,
+        dual: Quaternion {
+            x: T::ZERO,
+            y: T::ZERO,
+            z: T::ZERO,
+            w: T::ZERO,
+        },
+    };
+
+    /// Creates a motion that rotates first and then translates.
+    pub fn from_rotation_translation(rotation: Quaternion<T>, translation: Vector3<T>) -> Self {
+        let t = Quaternion::new(translation.x, translation.y, translation.z, T::ZERO);
+
+        DualQuaternion {
+            real: rotation,
+            dual: t * rotation * T::HALF,
+        }
+    }
+
+    /// Returns the rotation part.
+    pub fn rotation(&self) -> Quaternion<T> {
+        self.real
+    }
+
+    /// Returns the translation part.
+    ///
+    /// The dual quaternion must be normalized.
+    pub fn translation(&self) -> Vector3<T> {
+        let t = self.dual * self.real.conjugate() * T::TWO;
+        Vector3::new(t.x, t.y, t.z)
+    }
+
+    /// Returns a normalized dual quaternion.
+    ///
+    /// Real part becomes unit and dual part becomes orthogonal to it.
+    pub fn normalized(&self) -> Self {
+        let norm2 = self.real.norm2();
+        if norm2 == T::ZERO {
+            return *self;
+        }
+
+        let inv = norm2.sqrt().recip();
+        let real = self.real * inv;
+        let dual = self.dual * inv;
+
+        DualQuaternion {
+            real,
+            dual: dual - real * real.dot(&dual),
+        }
+    }
+
+    /// Normalizes the dual quaternion.
+    pub fn normalize(&mut self) {
+        *self = self.normalized();
+    }
+
+    /// Returns quaternion conjugate of both parts.
+    ///
+    /// For unit dual quaternions this is the inverse motion.
+    pub fn conjugate(&self) -> Self {
+        DualQuaternion {
+            real: self.real.conjugate(),
+            dual: self.dual.conjugate(),
+        }
+    }
+
+    /// Transforms the point by this motion.
+    ///
+    /// The dual quaternion must be normalized.
+    pub fn transform_point(&self, point: Vector3<T>) -> Vector3<T> {
+        let r = self.real.rotate(point);
+        let t = self.translation();
+        Vector3::new(r.x + t.x, r.y + t.y, r.z + t.z)
+    }
+}
+
+impl<T> Mul for DualQuaternion<T>
+where
+    T: Num,
+{
+    type Output = DualQuaternion<T>;
+
+    fn mul(self, rhs: DualQuaternion<T>) -> DualQuaternion<T> {
+        DualQuaternion {
+            real: self.real * rhs.real,
+            dual: self.real * rhs.dual + self.dual * rhs.real,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Motor3, Point3};
+
+    fn assert_close(a: Vector3<f64>, b: Vector3<f64>) {
+        let d = (a.x - b.x).abs() + (a.y - b.y).abs() + (a.z - b.z).abs();
+        assert!(d < 1e-12, "{a:?} != {b:?}");
+    }
+
+    fn moved(motor: Motor3<f64>, point: Vector3<f64>) -> Vector3<f64> {
+        let (x, y, z) = motor
+            .move_point(Point3::at(point.x, point.y, point.z))
+            .normalized()
+            .coords();
+        Vector3::new(x, y, z)
+    }
+
+    #[test]
+    fn quaternion_motor_round_trip() {
+        let q = Quaternion::from_axis_angle(Vector3::new(1.0, -2.0, 0.5), 1.3);
+        let motor = Motor3::from(q);
+
+        let point = Vector3::new(0.3, 2.0, -1.0);
+        assert_close(moved(motor, point), q.rotate(point));
+        assert_eq!(Quaternion::from(motor), q);
+    }
+
+    #[test]
+    fn matrix_round_trip() {
+        let q = Quaternion::from_axis_angle(Vector3::new(0.2, 1.0, -0.7), 2.5);
+        let r = Quaternion::from_matrix(&q.to_matrix());
+        assert!((q.dot(&r).abs() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn dual_quaternion_motor_round_trip() {
+        let rotation = Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 1.0), 0.8);
+        let dq = DualQuaternion::from_rotation_translation(rotation, Vector3::new(1.0, 2.0, 3.0));
+        let motor = Motor3::from(dq);
+
+        let point = Vector3::new(-1.0, 0.5, 4.0);
+        assert_close(moved(motor, point), dq.transform_point(point));
+        assert_eq!(DualQuaternion::from(motor), dq);
+    }
+
+    #[test]
+    fn screw_composition_matches() {
+        let a = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector3::new(1.0, 0.0, 0.0), 0.7),
+            Vector3::new(1.0, 0.0, 0.0),
+        );
+        let b = DualQuaternion::from_rotation_translation(
+            Quaternion::from_axis_angle(Vector3::new(0.0, 1.0, 0.0), -1.1),
+            Vector3::new(0.0, 2.0, 1.0),
+        );
+
+        let point = Vector3::new(0.5, -0.5, 2.0);
+        let motor = Motor3::from(a) * Motor3::from(b);
+        assert_close(moved(motor, point), (a * b).transform_point(point));
+    }
+}
@@ -76,6 +76,18 @@ pub trait Num:
     fn atan2(self, rhs: Self) -> Self;
 }
 
+/// Scalars with exponential and logarithm.
+///
+/// Kept apart from [`Num`] so that scalar types without them can still be used
+/// everywhere except the hyperbolic geometry.
+pub trait NumExp: Num {
+    /// Returns `e` raised to the power of the scalar.
+    fn exp(self) -> Self;
+
+    /// Returns the natural logarithm of the scalar.
+    fn ln(self) -> Self;
+}
+
 #[cfg(feature = "std")]
 impl Num for f32 {
     const ZERO: Self = 0.0;
@@ -140,6 +152,19 @@ impl Num for f32 {
     }
 }
 
+#[cfg(feature = "std")]
+impl NumExp for f32 {
+    #[inline]
+    fn exp(self) -> Self {
+        self.exp()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}
+
 #[cfg(feature = "std")]
 impl Num for f64 {
     const ZERO: Self = 0.0;
@@ -203,3 +228,16 @@ impl Num for f64 {
         self.atan2(rhs)
     }
 }
+
+#[cfg(feature = "std")]
+impl NumExp for f64 {
+    #[inline]
+    fn exp(self) -> Self {
+        self.exp()
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        self.ln()
+    }
+}